@@ -0,0 +1,150 @@
+//! Macros generating zome function boilerplate for implementations of the meta-traits traits.
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, FnArg, ImplItem, ImplItemMethod, ItemImpl, Pat, ReturnType, Type};
+
+/// Expose every method of a trait impl as a zome function.
+///
+/// ```ignore
+/// use hdk3::prelude::*;
+///
+/// #[expose_zome]
+/// impl SocialGraphDao for SocialGraph { ... }
+/// ```
+///
+/// For each method `follow(target_agent: Identity, by: Option<String>) -> ExternResult<()>` this generates
+/// an `#[hdk_extern] fn follow` taking `meta_traits::io::social_graph::FollowInput` which calls
+/// `<SocialGraph as SocialGraphDao>::follow` and returns its result as a `FollowOutput`, so the wire format is
+/// the one defined by the `io` module. Parameters must be named as they are in the trait.
+/// Errors returned by the method are passed through to the caller unchanged; methods which cannot fail, such as
+/// `SocialContextDao::writable`, always return Ok.
+/// The hdk3 prelude must be in scope where the macro is used, and meta_traits must be a dependency of the crate.
+#[proc_macro_attribute]
+pub fn expose_zome(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new(Span::call_site(), "expose_zome takes no arguments")
+            .to_compile_error()
+            .into();
+    }
+    let item_impl = parse_macro_input!(item as ItemImpl);
+    match expand(&item_impl) {
+        Ok(generated) => quote!(#item_impl #generated).into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(item_impl: &ItemImpl) -> syn::Result<TokenStream2> {
+    let trait_path = match &item_impl.trait_ {
+        Some((_, path, _)) => path,
+        None => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "expose_zome must be used on a trait impl",
+            ))
+        }
+    };
+    let trait_name = match trait_path.segments.last() {
+        Some(segment) => segment.ident.to_string(),
+        None => return Err(syn::Error::new_spanned(trait_path, "expected a trait name")),
+    };
+    let io_module = format_ident!("{}", io_module_name(&trait_name));
+    let self_ty = &item_impl.self_ty;
+    let mut generated = TokenStream2::new();
+    for impl_item in item_impl.items.iter() {
+        if let ImplItem::Method(method) = impl_item {
+            generated.extend(expand_method(method, self_ty, trait_path, &io_module)?);
+        }
+    }
+    Ok(generated)
+}
+
+fn expand_method(
+    method: &ImplItemMethod,
+    self_ty: &Type,
+    trait_path: &syn::Path,
+    io_module: &syn::Ident,
+) -> syn::Result<TokenStream2> {
+    let name = &method.sig.ident;
+    let camel = to_camel_case(&name.to_string());
+    let input_ident = format_ident!("{}Input", camel);
+    let output_ident = format_ident!("{}Output", camel);
+    let input_ty = quote!(::meta_traits::io::#io_module::#input_ident);
+    let output_ty = quote!(::meta_traits::io::#io_module::#output_ident);
+
+    let mut params = Vec::new();
+    for arg in method.sig.inputs.iter() {
+        match arg {
+            FnArg::Typed(pat_type) => match &*pat_type.pat {
+                Pat::Ident(pat_ident) => {
+                    params.push(pat_ident.ident.clone());
+                }
+                pat => {
+                    return Err(syn::Error::new_spanned(
+                        pat,
+                        "expose_zome requires named parameters",
+                    ))
+                }
+            },
+            FnArg::Receiver(receiver) => {
+                return Err(syn::Error::new_spanned(
+                    receiver,
+                    "methods exposed as zome functions cannot take self",
+                ))
+            }
+        }
+    }
+    let call = if returns_extern_result(&method.sig.output) {
+        quote!(<#self_ty as #trait_path>::#name(#(input.#params),*))
+    } else {
+        quote!(Ok(<#self_ty as #trait_path>::#name(#(input.#params),*)))
+    };
+
+    Ok(quote! {
+        #[hdk_extern]
+        fn #name(input: #input_ty) -> ExternResult<#output_ty> {
+            #call.map(#output_ty)
+        }
+    })
+}
+
+fn returns_extern_result(output: &ReturnType) -> bool {
+    if let ReturnType::Type(_, ty) = output {
+        if let Type::Path(type_path) = &**ty {
+            if let Some(segment) = type_path.path.segments.last() {
+                return segment.ident == "ExternResult";
+            }
+        }
+    }
+    false
+}
+
+/// Module of `meta_traits::io` holding the DTOs of a trait; "InterDNADao" -> "inter_dna"
+fn io_module_name(trait_name: &str) -> String {
+    let name = trait_name
+        .strip_suffix("Dao")
+        .unwrap_or(trait_name)
+        .replace("DNA", "Dna");
+    let mut module = String::new();
+    for (index, c) in name.chars().enumerate() {
+        if c.is_uppercase() && index > 0 {
+            module.push('_');
+        }
+        module.extend(c.to_lowercase());
+    }
+    module
+}
+
+fn to_camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,537 @@
+use hdk3::prelude::*;
+
+/// Conversion of a crate type into an AS2 JSON-LD object (or id, for reference types)
+pub trait ToActivityStreams {
+    fn to_activity_streams(&self) -> serde_json::Value;
+}
+
+/// Conversion of an AS2 JSON-LD value back into a crate type
+pub trait FromActivityStreams: Sized {
+    fn from_activity_streams(value: &serde_json::Value) -> Result<Self, crate::errors::TraitError>;
+}
+
+impl ToActivityStreams for Activity {
+    fn to_activity_streams(&self) -> serde_json::Value {
+        let mut activity = serde_json::to_value(self).unwrap_or(serde_json::Value::Null);
+        activity["@context"] = serde_json::Value::from(holochain_ap_context());
+        activity
+    }
+}
+
+impl FromActivityStreams for Activity {
+    fn from_activity_streams(value: &serde_json::Value) -> Result<Self, crate::errors::TraitError> {
+        serde_json::from_value(value.clone())
+            .map_err(|err| crate::errors::TraitError::InvalidInput(err.to_string()))
+    }
+}
+
+/// Properties shared by every activity. Serializes to the AS2 JSON-LD shape so activities can be passed
+/// straight to/from federation without conversion
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ActivityFields {
+    pub id: Option<String>,
+    pub actor: String,
+    /// Object of the activity; either an inline AS2 object or the id of one
+    pub object: serde_json::Value,
+    /// Id of the object the activity is directed at; i.e the new actor of a Move
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    #[serde(default)]
+    pub to: Vec<String>,
+    #[serde(default)]
+    pub cc: Vec<String>,
+    /// Private recipients; must be removed with strip_private_recipients before the activity is stored publicly or federated
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bto: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bcc: Vec<String>,
+    pub published: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// AS2 activities which DNAs implementing the traits of this crate can produce or consume
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type")]
+pub enum Activity {
+    Create(ActivityFields),
+    Update(ActivityFields),
+    Delete(ActivityFields),
+    Follow(ActivityFields),
+    Accept(ActivityFields),
+    Reject(ActivityFields),
+    TentativeAccept(ActivityFields),
+    Like(ActivityFields),
+    Announce(ActivityFields),
+    Undo(ActivityFields),
+    Block(ActivityFields),
+    Move(ActivityFields),
+}
+
+impl Activity {
+    /// AS2 type name of this activity
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Activity::Create(_) => "Create",
+            Activity::Update(_) => "Update",
+            Activity::Delete(_) => "Delete",
+            Activity::Follow(_) => "Follow",
+            Activity::Accept(_) => "Accept",
+            Activity::Reject(_) => "Reject",
+            Activity::TentativeAccept(_) => "TentativeAccept",
+            Activity::Like(_) => "Like",
+            Activity::Announce(_) => "Announce",
+            Activity::Undo(_) => "Undo",
+            Activity::Block(_) => "Block",
+            Activity::Move(_) => "Move",
+        }
+    }
+
+    pub fn fields(&self) -> &ActivityFields {
+        match self {
+            Activity::Create(fields)
+            | Activity::Update(fields)
+            | Activity::Delete(fields)
+            | Activity::Follow(fields)
+            | Activity::Accept(fields)
+            | Activity::Reject(fields)
+            | Activity::TentativeAccept(fields)
+            | Activity::Like(fields)
+            | Activity::Announce(fields)
+            | Activity::Undo(fields)
+            | Activity::Block(fields)
+            | Activity::Move(fields) => fields,
+        }
+    }
+
+    pub fn fields_mut(&mut self) -> &mut ActivityFields {
+        match self {
+            Activity::Create(fields)
+            | Activity::Update(fields)
+            | Activity::Delete(fields)
+            | Activity::Follow(fields)
+            | Activity::Accept(fields)
+            | Activity::Reject(fields)
+            | Activity::TentativeAccept(fields)
+            | Activity::Like(fields)
+            | Activity::Announce(fields)
+            | Activity::Undo(fields)
+            | Activity::Block(fields)
+            | Activity::Move(fields) => fields,
+        }
+    }
+}
+
+/// Blind recipients of an activity, kept apart from the activity once it has been stripped
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PrivateAudience {
+    pub bto: Vec<String>,
+    pub bcc: Vec<String>,
+}
+
+impl PrivateAudience {
+    pub fn is_empty(&self) -> bool {
+        self.bto.is_empty() && self.bcc.is_empty()
+    }
+
+    /// Every recipient the activity should be delivered to; public recipients followed by private ones
+    pub fn delivery_targets(&self, activity: &Activity) -> Vec<String> {
+        let fields = activity.fields();
+        let mut targets: Vec<String> = Vec::new();
+        for recipient in fields
+            .to
+            .iter()
+            .chain(fields.cc.iter())
+            .chain(self.bto.iter())
+            .chain(self.bcc.iter())
+        {
+            if !targets.contains(recipient) {
+                targets.push(recipient.clone());
+            }
+        }
+        targets
+    }
+}
+
+impl Activity {
+    /// Remove bto/bcc recipients from activity and return them. Must be called before an activity is written to
+    /// the DHT or sent to any inbox, so that blind recipients are never revealed
+    pub fn strip_private_recipients(&mut self) -> PrivateAudience {
+        let fields = self.fields_mut();
+        PrivateAudience {
+            bto: std::mem::take(&mut fields.bto),
+            bcc: std::mem::take(&mut fields.bcc),
+        }
+    }
+}
+
+/// ActivityPub shaped interface onto an agent's outgoing activity. Implementations translate posted activities
+/// into calls on the DNA's native traits; i.e Create of a Note into ExpressionDao::create_public_expression
+pub trait ApOutboxDao {
+    /// Perform activity as current agent; returns activity with its id assigned
+    fn post_activity(activity: Activity) -> ExternResult<Activity>;
+    /// Undo a previous Follow, Like or Announce of current agent; returns the posted Undo activity
+    fn undo(target: UndoTarget) -> ExternResult<Activity>;
+    /// Get activities performed by current agent, newest first
+    fn outbox(count: usize, page: usize) -> ExternResult<Vec<Activity>>;
+}
+
+/// Native trait method an incoming activity is translated into
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DispatchTarget {
+    /// Name of a trait in this crate; i.e "SocialGraphDao"
+    pub trait_name: String,
+    pub method: String,
+}
+
+impl DispatchTarget {
+    pub fn new(trait_name: &str, method: &str) -> Self {
+        DispatchTarget {
+            trait_name: String::from(trait_name),
+            method: String::from(method),
+        }
+    }
+}
+
+/// Mapping of AS2 activity types to the native trait methods they invoke when delivered to an inbox.
+/// Activity types without a rule are ignored. Undo activities are dispatched by the "Undo" rule like any other
+/// type; its target can work out what is being undone with UndoTarget::from_undo.
+///
+/// Rules are empty by default. Inbound activities are performed by a remote actor on the local agent, while the
+/// native traits act as the local agent; i.e SocialGraphDao::follow would follow the remote actor back rather than
+/// record them as a follower. Hosts must supply rules targeting methods which record remote actions.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DispatchRules {
+    pub rules: Vec<(String, DispatchTarget)>,
+}
+
+impl DispatchRules {
+    pub fn target_for(&self, activity: &Activity) -> Option<DispatchTarget> {
+        self.rules
+            .iter()
+            .find(|(kind, _)| kind == activity.kind())
+            .map(|(_, target)| target.clone())
+    }
+
+    /// Set the target for an activity type, replacing any existing rule for it
+    pub fn with_rule(mut self, kind: &str, target: DispatchTarget) -> Self {
+        self.rules.retain(|(existing, _)| existing != kind);
+        self.rules.push((String::from(kind), target));
+        self
+    }
+}
+
+/// Outcome of delivering an activity to an inbox
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum InboxResult {
+    /// Activity was translated into a call of target
+    Dispatched(DispatchTarget),
+    /// Activity was stored but there is no rule for its type
+    Ignored,
+    Rejected {
+        reason: String,
+    },
+}
+
+/// ActivityPub shaped interface onto an agent's incoming activity. A bridge delivers federated activities here
+/// and the implementation invokes native trait methods according to its dispatch_rules, so translation of
+/// federation traffic into zome calls is deterministic.
+pub trait ApInboxDao {
+    fn deliver(activity: Activity) -> ExternResult<InboxResult>;
+    /// Get activities delivered to current agent, newest first
+    fn inbox(count: usize, page: usize) -> ExternResult<Vec<Activity>>;
+    fn dispatch_rules() -> ExternResult<DispatchRules>;
+}
+
+/// AS2 publicKey block of an actor used to verify HTTP Signatures of federated requests
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PublicKey {
+    /// Key id; conventionally the actor id with a "#main-key" fragment
+    pub id: String,
+    /// Id of the actor owning this key
+    pub owner: String,
+    pub public_key_pem: String,
+}
+
+/// Interface for the key pair an agent is represented by in the ActivityPub world. Holochain agent keys
+/// are ed25519 whereas HTTP Signatures generally expect RSA, so a separate key pair is kept per agent.
+pub trait KeyManagementDao {
+    /// Replace current agent's key pair; the previous key is no longer valid for signing
+    fn rotate_key() -> ExternResult<PublicKey>;
+    fn current_key() -> ExternResult<PublicKey>;
+    /// Sign bytes with current agent's private key
+    fn sign(bytes: Vec<u8>) -> ExternResult<Vec<u8>>;
+    /// Verify signature of bytes against the current key of actor
+    fn verify(actor: String, bytes: Vec<u8>, signature: Vec<u8>) -> ExternResult<bool>;
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WebFingerLink {
+    pub rel: String,
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    pub href: Option<String>,
+}
+
+/// JRD document returned for a WebFinger lookup
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WebFingerResource {
+    pub subject: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    #[serde(default)]
+    pub links: Vec<WebFingerLink>,
+}
+
+/// Interface allowing a gateway to answer `acct:user@domain` WebFinger lookups for agents of a profile DNA
+pub trait WebFingerDao {
+    /// Get WebFinger document for acct; None if no agent is registered under it
+    fn resource_for(acct: String) -> ExternResult<Option<WebFingerResource>>;
+    /// Resolve acct to the agent and profile DNA it refers to
+    fn resolve_acct(acct: String) -> ExternResult<Option<crate::handle::Handle>>;
+}
+
+pub const ACTIVITY_STREAMS_CONTEXT: &str = "https://www.w3.org/ns/activitystreams";
+pub const SECURITY_CONTEXT: &str = "https://w3id.org/security/v1";
+/// IRI prefix of the JSON-LD terms this crate adds to AS2
+pub const HOLOCHAIN_AP_NAMESPACE: &str = "https://junto.foundation/ns#";
+
+/// JSON-LD `@context` of an AS2 document. Serializes as a single url, an array of urls or an array of
+/// urls followed by an object of extension term definitions
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(try_from = "serde_json::Value", into = "serde_json::Value")]
+pub enum JsonLdContext {
+    Single(String),
+    Multiple(Vec<String>),
+    WithExtensions {
+        urls: Vec<String>,
+        terms: serde_json::Map<String, serde_json::Value>,
+    },
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for JsonLdContext {
+    fn schema_name() -> String {
+        String::from("JsonLdContext")
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <serde_json::Value>::json_schema(gen)
+    }
+}
+
+impl From<JsonLdContext> for serde_json::Value {
+    fn from(context: JsonLdContext) -> serde_json::Value {
+        match context {
+            JsonLdContext::Single(url) => serde_json::Value::String(url),
+            JsonLdContext::Multiple(urls) => {
+                serde_json::Value::Array(urls.into_iter().map(serde_json::Value::String).collect())
+            }
+            JsonLdContext::WithExtensions { urls, terms } => serde_json::Value::Array(
+                urls.into_iter()
+                    .map(serde_json::Value::String)
+                    .chain(std::iter::once(serde_json::Value::Object(terms)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl std::convert::TryFrom<serde_json::Value> for JsonLdContext {
+    type Error = String;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        match value {
+            serde_json::Value::String(url) => Ok(JsonLdContext::Single(url)),
+            serde_json::Value::Object(terms) => Ok(JsonLdContext::WithExtensions {
+                urls: vec![],
+                terms,
+            }),
+            serde_json::Value::Array(items) => {
+                let mut urls = Vec::new();
+                let mut terms = serde_json::Map::new();
+                for item in items {
+                    match item {
+                        serde_json::Value::String(url) => urls.push(url),
+                        // Documents in the wild may split term definitions over several objects
+                        serde_json::Value::Object(object) => terms.extend(object),
+                        other => return Err(format!("invalid @context entry {}", other)),
+                    }
+                }
+                if terms.is_empty() {
+                    Ok(JsonLdContext::Multiple(urls))
+                } else {
+                    Ok(JsonLdContext::WithExtensions { urls, terms })
+                }
+            }
+            other => Err(format!("invalid @context {}", other)),
+        }
+    }
+}
+
+/// `@context` for AS2 documents produced by this crate, defining its extension terms
+pub fn holochain_ap_context() -> JsonLdContext {
+    let mut terms = serde_json::Map::new();
+    terms.insert(
+        String::from("hc"),
+        serde_json::Value::String(String::from(HOLOCHAIN_AP_NAMESPACE)),
+    );
+    for term in &[
+        "dna",
+        "globalEntryRef",
+        "linkType",
+        "expressionDna",
+        "agent",
+    ] {
+        terms.insert(
+            String::from(*term),
+            serde_json::Value::String(format!("hc:{}", term)),
+        );
+    }
+    JsonLdContext::WithExtensions {
+        urls: vec![
+            String::from(ACTIVITY_STREAMS_CONTEXT),
+            String::from(SECURITY_CONTEXT),
+        ],
+        terms,
+    }
+}
+
+/// Outbound federation of an activity to a set of remote inboxes
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Delivery {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub id: HeaderHash,
+    pub activity: Activity,
+    /// Inbox urls the activity has not yet been delivered to
+    pub inboxes: Vec<String>,
+    pub attempts: u32,
+    /// When the bridge should next attempt delivery; None if it can be attempted immediately
+    pub retry_after: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Queue of activities awaiting delivery to remote ActivityPub inboxes. HTTP delivery happens off-chain in a bridge
+/// but the queue state lives in the DNA so that any bridge instance can pick up pending deliveries.
+pub trait ApDeliveryDao {
+    fn enqueue(activity: Activity, inboxes: Vec<String>) -> ExternResult<Delivery>;
+    /// Get deliveries which are due, oldest first
+    fn pending_deliveries(count: usize, page: usize) -> ExternResult<Vec<Delivery>>;
+    fn mark_delivered(id: HeaderHash) -> ExternResult<()>;
+    /// Record a failed attempt; delivery will not be returned by pending_deliveries before retry_after
+    fn mark_failed(
+        id: HeaderHash,
+        retry_after: chrono::DateTime<chrono::Utc>,
+    ) -> ExternResult<Delivery>;
+}
+
+/// Activity which can be undone, identified by what it was performed on
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum UndoTarget {
+    Follow { actor: String },
+    Like { object: String },
+    Announce { object: String },
+}
+
+impl UndoTarget {
+    /// Work out what an incoming Undo activity undoes. Its object must be the inline activity being undone;
+    /// an Undo referencing the undone activity only by id cannot be resolved
+    pub fn from_undo(activity: &Activity) -> Option<UndoTarget> {
+        let undone = match activity {
+            Activity::Undo(fields) => fields.object.as_object()?,
+            _ => return None,
+        };
+        let object = undone.get("object").and_then(|object| match object {
+            serde_json::Value::String(id) => Some(id.clone()),
+            serde_json::Value::Object(inline) => inline
+                .get("id")
+                .and_then(|id| id.as_str())
+                .map(String::from),
+            _ => None,
+        })?;
+        match undone.get("type").and_then(|kind| kind.as_str())? {
+            "Follow" => Some(UndoTarget::Follow { actor: object }),
+            "Like" => Some(UndoTarget::Like { object }),
+            "Announce" => Some(UndoTarget::Announce { object }),
+            _ => None,
+        }
+    }
+
+    /// Undo activity by actor for this target
+    pub fn to_activity(&self, actor: String) -> Activity {
+        let (kind, object) = match self {
+            UndoTarget::Follow { actor: followed } => ("Follow", followed),
+            UndoTarget::Like { object } => ("Like", object),
+            UndoTarget::Announce { object } => ("Announce", object),
+        };
+        Activity::Undo(ActivityFields {
+            id: None,
+            actor: actor.clone(),
+            object: serde_json::json!({
+                "type": kind,
+                "actor": actor,
+                "object": object,
+            }),
+            target: None,
+            to: vec![],
+            cc: vec![],
+            bto: vec![],
+            bcc: vec![],
+            published: None,
+        })
+    }
+}
+
+/// Interface for migrating an agent's actor between DNAs or to/from traditional ActivityPub servers
+/// following Mastodon's Move/alsoKnownAs flow. Actors are identified by their AS2 id.
+pub trait ActorMigrationDao {
+    /// Post a Move activity to followers announcing current agent has moved to actor `to`; its object is current
+    /// agent's actor and its target is `to`.
+    /// `to` must list current agent's actor in its also_known_as for followers to accept the move
+    fn announce_move(to: String) -> ExternResult<Activity>;
+    /// Actor current agent has moved to, if any
+    fn moved_to() -> ExternResult<Option<String>>;
+    fn also_known_as() -> ExternResult<Vec<String>>;
+    /// Declare other actors as aliases of current agent; required before moving from one of them
+    fn set_also_known_as(aliases: Vec<String>) -> ExternResult<()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_rules_do_not_dispatch_inbound_undo_of_follow() {
+        let undo = UndoTarget::Follow {
+            actor: String::from("https://example.com/actors/local"),
+        }
+        .to_activity(String::from("https://example.com/actors/remote"));
+        assert_eq!(
+            UndoTarget::from_undo(&undo),
+            Some(UndoTarget::Follow {
+                actor: String::from("https://example.com/actors/local")
+            })
+        );
+        assert_eq!(DispatchRules::default().target_for(&undo), None);
+    }
+
+    #[test]
+    fn undo_uses_configured_rule() {
+        let target = DispatchTarget::new("FollowersDao", "remove_follower");
+        let rules = DispatchRules::default().with_rule("Undo", target.clone());
+        let undo = UndoTarget::Announce {
+            object: String::from("https://example.com/notes/1"),
+        }
+        .to_activity(String::from("https://example.com/actors/remote"));
+        assert_eq!(rules.target_for(&undo), Some(target));
+    }
+}
@@ -0,0 +1,45 @@
+//! Deterministic serialization of crate types, for when both sides of a countersigning or a mirror must derive
+//! identical bytes and hashes from the same value. The canonical form is compact JSON with object keys sorted
+//! lexicographically at every level.
+use hdk3::prelude::*;
+
+use crate::errors::TraitError;
+
+/// Sort object keys explicitly rather than relying on serde_json's map ordering, which changes if any crate in
+/// the build enables its preserve_order feature
+fn sort_keys(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(object) => {
+            let mut entries: Vec<(String, serde_json::Value)> = object.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            serde_json::Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, sort_keys(value)))
+                    .collect(),
+            )
+        }
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.into_iter().map(sort_keys).collect())
+        }
+        other => other,
+    }
+}
+
+pub fn canonical_string<T: Serialize>(value: &T) -> Result<String, TraitError> {
+    let value =
+        serde_json::to_value(value).map_err(|err| TraitError::InvalidInput(err.to_string()))?;
+    serde_json::to_string(&sort_keys(value)).map_err(|err| TraitError::Internal(err.to_string()))
+}
+
+pub fn canonical_bytes<T: Serialize>(value: &T) -> Result<Vec<u8>, TraitError> {
+    canonical_string(value).map(String::into_bytes)
+}
+
+/// Blake2b-256 hash of the canonical bytes of value, with the DHT location bytes holochain appends to every hash
+pub fn canonical_hash<T: Serialize>(value: &T) -> Result<EntryHash, TraitError> {
+    let bytes = canonical_bytes(value)?;
+    let mut hash = holo_hash::encode::blake2b_256(&bytes);
+    hash.extend(holo_hash::encode::holo_dht_location_bytes(&hash));
+    Ok(EntryHash::from_raw_bytes(hash))
+}
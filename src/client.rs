@@ -0,0 +1,537 @@
+//! Callers for DNAs implementing the core traits, for use outside of wasm; i.e by UIs' bridge services.
+//! Each client has a method for every method of its trait which makes the zome call of the same name, sending
+//! and receiving the DTOs in `io` as the zome functions generated by `expose_zome` do.
+//!
+//! The traits are implemented by zomes with associated functions, so clients mirror them with methods instead of
+//! implementing them.
+use hdk3::prelude::*;
+use holo_hash::DnaHash;
+use holochain_conductor_api::{AppRequest, AppResponse, ZomeCallInvocation};
+use holochain_websocket::{websocket_connect, WebsocketConfig, WebsocketSender};
+use serde::de::DeserializeOwned;
+use std::sync::{Arc, Mutex};
+use url2::Url2;
+
+use crate::errors::TraitError;
+use crate::io;
+use crate::{
+    Audience, BatchResult, ContextInvite, DeliveryStatus, Expression, ExpressionFilter,
+    ExpressionPage, GlobalEntryRef, Identity, InterDnaLink, LanguageTag, LinkType, LinksByDna,
+    Nonce, PendingMessage, SocialContextProfile, Tombstone,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClientError {
+    /// Could not connect or talk to the conductor
+    Connection(String),
+    /// Zome function failed with a TraitError
+    Trait(TraitError),
+    /// Conductor or zome returned an error for the call which is not a TraitError
+    Conductor(String),
+    Serialization(String),
+}
+
+impl ClientError {
+    /// Error for an error response of the conductor, decoding any TraitError the zome function failed with
+    pub fn from_conductor_error<E: std::fmt::Debug>(error: E) -> Self {
+        let message = format!("{:?}", error);
+        match TraitError::from_zome_error(&message) {
+            Some(error) => ClientError::Trait(error),
+            None => ClientError::Conductor(message),
+        }
+    }
+}
+
+/// Makes zome calls against a single zome of a single cell
+pub trait ZomeCaller {
+    fn call(&self, fn_name: &str, payload: SerializedBytes)
+        -> Result<SerializedBytes, ClientError>;
+}
+
+/// ZomeCaller using a conductor's app interface websocket. Calls block until the conductor responds
+pub struct WebsocketCaller {
+    runtime: Mutex<tokio::runtime::Runtime>,
+    sender: WebsocketSender,
+    cell_id: CellId,
+    zome_name: ZomeName,
+}
+
+impl WebsocketCaller {
+    /// Connect to the app interface at conductor_url; calls are made as the agent of cell_id
+    pub fn connect(
+        conductor_url: &str,
+        cell_id: CellId,
+        zome_name: &str,
+    ) -> Result<Self, ClientError> {
+        let url = Url2::try_parse(conductor_url)
+            .map_err(|err| ClientError::Connection(err.to_string()))?;
+        let mut runtime = tokio::runtime::Runtime::new()
+            .map_err(|err| ClientError::Connection(err.to_string()))?;
+        let (sender, _receiver) = runtime
+            .block_on(websocket_connect(url, Arc::new(WebsocketConfig::default())))
+            .map_err(|err| ClientError::Connection(err.to_string()))?;
+        Ok(WebsocketCaller {
+            runtime: Mutex::new(runtime),
+            sender,
+            cell_id,
+            zome_name: ZomeName::from(zome_name),
+        })
+    }
+}
+
+impl ZomeCaller for WebsocketCaller {
+    fn call(
+        &self,
+        fn_name: &str,
+        payload: SerializedBytes,
+    ) -> Result<SerializedBytes, ClientError> {
+        let request = AppRequest::ZomeCallInvocation(Box::new(ZomeCallInvocation {
+            cell_id: self.cell_id.clone(),
+            zome_name: self.zome_name.clone(),
+            cap: None,
+            fn_name: FunctionName::from(fn_name),
+            payload: ExternInput::new(payload),
+            provenance: self.cell_id.agent_pubkey().clone(),
+        }));
+        let mut sender = self.sender.clone();
+        let response: AppResponse = self
+            .runtime
+            .lock()
+            .map_err(|err| ClientError::Connection(err.to_string()))?
+            .block_on(sender.request(request))
+            .map_err(|err| ClientError::Connection(err.to_string()))?;
+        match response {
+            AppResponse::ZomeCallInvocation(output) => Ok(output.into_inner()),
+            AppResponse::Error(err) => Err(ClientError::from_conductor_error(err)),
+            other => Err(ClientError::Conductor(format!(
+                "unexpected response: {:?}",
+                other
+            ))),
+        }
+    }
+}
+
+fn call<C, I, O>(caller: &C, fn_name: &str, input: &I) -> Result<O, ClientError>
+where
+    C: ZomeCaller,
+    I: Serialize + std::fmt::Debug,
+    O: DeserializeOwned,
+{
+    let payload = holochain_serialized_bytes::encode(input)
+        .map_err(|err| ClientError::Serialization(err.to_string()))?;
+    let response = caller.call(fn_name, SerializedBytes::from(UnsafeBytes::from(payload)))?;
+    holochain_serialized_bytes::decode(response.bytes())
+        .map_err(|err| ClientError::Serialization(err.to_string()))
+}
+
+/// Iterator over every item of a paged collection, fetching each page when the previous one is exhausted.
+/// fetch returns a page of items and the position of the next page, or None after the last page. Iteration
+/// also ends at an empty page or a position which was already fetched, so that a DNA which keeps returning a
+/// next page cannot make it loop forever
+pub struct PageIter<T, P, F> {
+    fetch: F,
+    next_page: Option<P>,
+    fetched: Vec<P>,
+    items: std::vec::IntoIter<T>,
+}
+
+impl<T, P, F> PageIter<T, P, F>
+where
+    P: PartialEq + Clone,
+    F: FnMut(P) -> Result<(Vec<T>, Option<P>), ClientError>,
+{
+    pub fn new(first_page: P, fetch: F) -> Self {
+        PageIter {
+            fetch,
+            next_page: Some(first_page),
+            fetched: Vec::new(),
+            items: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl<T, P, F> Iterator for PageIter<T, P, F>
+where
+    P: PartialEq + Clone,
+    F: FnMut(P) -> Result<(Vec<T>, Option<P>), ClientError>,
+{
+    type Item = Result<T, ClientError>;
+
+    /// Yields a failed page fetch as an error and then ends
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.items.next() {
+                return Some(Ok(item));
+            }
+            let page = self.next_page.take()?;
+            if self.fetched.contains(&page) {
+                return None;
+            }
+            self.fetched.push(page.clone());
+            match (self.fetch)(page) {
+                Ok((items, _)) if items.is_empty() => return None,
+                Ok((items, next_page)) => {
+                    self.items = items.into_iter();
+                    self.next_page = next_page;
+                }
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+/// Position of the page after page, for collections paged by count and page number
+fn next_numbered_page<T>(items: &[T], count: usize, page: usize) -> Option<usize> {
+    if count > 0 && items.len() == count {
+        Some(page + 1)
+    } else {
+        None
+    }
+}
+
+macro_rules! zome_client {
+    ($(#[$meta:meta])* $client:ident($module:ident) {
+        $(fn $name:ident($($param:ident: $ty:ty),*) -> $out:ty = $input:ident, $output:ident;)*
+    }) => {
+        $(#[$meta])*
+        pub struct $client<C: ZomeCaller> {
+            caller: C,
+        }
+
+        impl $client<WebsocketCaller> {
+            pub fn new(conductor_url: &str, cell_id: CellId, zome_name: &str) -> Result<Self, ClientError> {
+                Ok($client::with_caller(WebsocketCaller::connect(conductor_url, cell_id, zome_name)?))
+            }
+        }
+
+        impl<C: ZomeCaller> $client<C> {
+            pub fn with_caller(caller: C) -> Self {
+                $client { caller }
+            }
+
+            $(
+                pub fn $name(&self, $($param: $ty),*) -> Result<$out, ClientError> {
+                    let output: io::$module::$output =
+                        call(&self.caller, stringify!($name), &io::$module::$input { $($param,)* })?;
+                    Ok(output.0)
+                }
+            )*
+        }
+    };
+}
+
+zome_client! {
+    /// Client for a DNA implementing SocialGraphDao
+    SocialGraphClient(social_graph) {
+        fn my_followers(by: Option<String>) -> Vec<Identity> = MyFollowersInput, MyFollowersOutput;
+        fn followers(followed_agent: Identity, by: Option<String>) -> Vec<Identity>
+            = FollowersInput, FollowersOutput;
+        fn nth_level_followers(n: usize, followed_agent: Identity, by: Option<String>) -> Vec<Identity>
+            = NthLevelFollowersInput, NthLevelFollowersOutput;
+        fn my_followings(by: Option<String>) -> Vec<Identity>
+            = MyFollowingsInput, MyFollowingsOutput;
+        fn following(following_agent: Identity, by: Option<String>) -> Vec<Identity>
+            = FollowingInput, FollowingOutput;
+        fn nth_level_following(n: usize, following_agent: Identity, by: Option<String>) -> Vec<Identity>
+            = NthLevelFollowingInput, NthLevelFollowingOutput;
+        fn follow(target_agent: Identity, by: Option<String>) -> () = FollowInput, FollowOutput;
+        fn unfollow(target_agent: Identity, by: Option<String>) -> ()
+            = UnfollowInput, UnfollowOutput;
+        fn my_friends() -> Vec<Identity> = MyFriendsInput, MyFriendsOutput;
+        fn friends_of(agent: Identity) -> Vec<Identity> = FriendsOfInput, FriendsOfOutput;
+        fn request_friendship(target_agent: Identity) -> ()
+            = RequestFriendshipInput, RequestFriendshipOutput;
+        fn decline_friendship(target_agent: Identity) -> ()
+            = DeclineFriendshipInput, DeclineFriendshipOutput;
+        fn incoming_friendship_requests() -> Vec<Identity>
+            = IncomingFriendshipRequestsInput, IncomingFriendshipRequestsOutput;
+        fn outgoing_friendship_requests() -> Vec<Identity>
+            = OutgoingFriendshipRequestsInput, OutgoingFriendshipRequestsOutput;
+        fn drop_friendship(target_agent: Identity) -> ()
+            = DropFriendshipInput, DropFriendshipOutput;
+    }
+}
+
+zome_client! {
+    /// Client for a DNA implementing SocialContextDao
+    SocialContextClient(social_context) {
+        fn post(expression_ref: GlobalEntryRef) -> () = PostInput, PostOutput;
+        fn register_communication_method(dna_address: DnaHash) -> ()
+            = RegisterCommunicationMethodInput, RegisterCommunicationMethodOutput;
+        fn writable() -> bool = WritableInput, WritableOutput;
+        fn read_communications(
+            by_dna: Option<DnaHash>,
+            by_agent: Option<Identity>,
+            count: usize,
+            page: usize
+        ) -> Vec<GlobalEntryRef> = ReadCommunicationsInput, ReadCommunicationsOutput;
+        fn get_communication_methods(count: usize, page: usize) -> Vec<DnaHash>
+            = GetCommunicationMethodsInput, GetCommunicationMethodsOutput;
+        fn members(count: usize, page: usize) -> Option<Vec<Identity>>
+            = MembersInput, MembersOutput;
+        fn join() -> () = JoinInput, JoinOutput;
+        fn leave() -> () = LeaveInput, LeaveOutput;
+        fn is_member(agent: Identity) -> bool = IsMemberInput, IsMemberOutput;
+        fn invite(agent: Identity) -> ContextInvite = InviteInput, InviteOutput;
+        fn accept_invite(invite: HeaderHash) -> () = AcceptInviteInput, AcceptInviteOutput;
+        fn decline_invite(invite: HeaderHash) -> () = DeclineInviteInput, DeclineInviteOutput;
+        fn pending_invites() -> Vec<ContextInvite> = PendingInvitesInput, PendingInvitesOutput;
+        fn get_context_info() -> SocialContextProfile = GetContextInfoInput, GetContextInfoOutput;
+        fn update_context_info(profile: SocialContextProfile) -> SocialContextProfile
+            = UpdateContextInfoInput, UpdateContextInfoOutput;
+        fn pin_expression(entry: GlobalEntryRef) -> () = PinExpressionInput, PinExpressionOutput;
+        fn unpin_expression(entry: GlobalEntryRef) -> ()
+            = UnpinExpressionInput, UnpinExpressionOutput;
+        fn pinned() -> Vec<GlobalEntryRef> = PinnedInput, PinnedOutput;
+    }
+}
+
+zome_client! {
+    /// Client for a DNA implementing ExpressionDao
+    ExpressionClient(expression) {
+        fn create_public_expression(content: String, audience: Audience) -> Expression
+            = CreatePublicExpressionInput, CreatePublicExpressionOutput;
+        fn get_by_author(
+            author: Identity,
+            filter: ExpressionFilter,
+            page_size: usize,
+            cursor: Option<String>
+        ) -> ExpressionPage = GetByAuthorInput, GetByAuthorOutput;
+        fn get_expression_by_address(address: AnyDhtHash) -> Option<Expression>
+            = GetExpressionByAddressInput, GetExpressionByAddressOutput;
+        fn get_many_by_address(addresses: Vec<AnyDhtHash>) -> Vec<Option<Expression>>
+            = GetManyByAddressInput, GetManyByAddressOutput;
+        fn delete_expression(address: AnyDhtHash) -> Tombstone
+            = DeleteExpressionInput, DeleteExpressionOutput;
+        fn is_deleted(address: AnyDhtHash) -> bool = IsDeletedInput, IsDeletedOutput;
+        fn tombstones_since(timestamp: chrono::DateTime<chrono::Utc>) -> Vec<Tombstone>
+            = TombstonesSinceInput, TombstonesSinceOutput;
+        fn boost(expression: GlobalEntryRef) -> Expression = BoostInput, BoostOutput;
+        fn quote(expression: GlobalEntryRef, commentary: String) -> Expression
+            = QuoteInput, QuoteOutput;
+        fn boosts_of(expression: GlobalEntryRef, count: usize, page: usize) -> Vec<Expression>
+            = BoostsOfInput, BoostsOfOutput;
+        fn quotes_of(expression: GlobalEntryRef, count: usize, page: usize) -> Vec<Expression>
+            = QuotesOfInput, QuotesOfOutput;
+        fn send_private(to: Identity, content: String) -> DeliveryStatus
+            = SendPrivateInput, SendPrivateOutput;
+        fn pending_outbox() -> Vec<PendingMessage> = PendingOutboxInput, PendingOutboxOutput;
+        fn inbox(from: Option<Identity>, page_size: usize, page_number: usize) -> Vec<Expression>
+            = InboxInput, InboxOutput;
+        fn inbox_paged(from: Option<Identity>, page_size: usize, cursor: Option<String>) -> ExpressionPage
+            = InboxPagedInput, InboxPagedOutput;
+        fn mark_read(expression: AnyDhtHash) -> () = MarkReadInput, MarkReadOutput;
+        fn unread_count() -> usize = UnreadCountInput, UnreadCountOutput;
+        fn delete_from_inbox(expression: AnyDhtHash) -> ()
+            = DeleteFromInboxInput, DeleteFromInboxOutput;
+    }
+}
+
+zome_client! {
+    /// Client for a DNA implementing InterDNADao
+    InterDnaClient(inter_dna) {
+        fn create_link(
+            source: GlobalEntryRef,
+            target: GlobalEntryRef,
+            link_type: LinkType,
+            metadata: Option<String>,
+            proof_of_work: Option<Nonce>
+        ) -> InterDnaLink = CreateLinkInput, CreateLinkOutput;
+        fn create_links(links: Vec<(GlobalEntryRef, GlobalEntryRef, LinkType, Option<Nonce>)>)
+            -> BatchResult = CreateLinksInput, CreateLinksOutput;
+        fn remove_link(source: GlobalEntryRef, target: GlobalEntryRef) -> InterDnaLink
+            = RemoveLinkInput, RemoveLinkOutput;
+        fn get_outgoing(
+            source: GlobalEntryRef,
+            link_type: Option<LinkType>,
+            count: usize,
+            page: usize
+        ) -> Vec<GlobalEntryRef> = GetOutgoingInput, GetOutgoingOutput;
+        fn get_incoming(
+            target: GlobalEntryRef,
+            link_type: Option<LinkType>,
+            count: usize,
+            page: usize
+        ) -> Vec<GlobalEntryRef> = GetIncomingInput, GetIncomingOutput;
+        fn get_outgoing_multi(
+            source: GlobalEntryRef,
+            dnas: Vec<DnaHash>,
+            link_type: Option<LinkType>,
+            count: usize,
+            page: usize
+        ) -> Vec<LinksByDna> = GetOutgoingMultiInput, GetOutgoingMultiOutput;
+        fn get_all_outgoing(source: GlobalEntryRef, count: usize, page: usize) -> Vec<LinksByDna>
+            = GetAllOutgoingInput, GetAllOutgoingOutput;
+        fn count_outgoing(source: GlobalEntryRef, link_type: Option<LinkType>) -> usize
+            = CountOutgoingInput, CountOutgoingOutput;
+        fn count_incoming(target: GlobalEntryRef, link_type: Option<LinkType>) -> usize
+            = CountIncomingInput, CountIncomingOutput;
+        fn translations_of(expression: GlobalEntryRef) -> Vec<(LanguageTag, GlobalEntryRef)>
+            = TranslationsOfInput, TranslationsOfOutput;
+    }
+}
+
+impl<C: ZomeCaller> SocialContextClient<C> {
+    pub fn read_communications_iter(
+        &self,
+        by_dna: Option<DnaHash>,
+        by_agent: Option<Identity>,
+        count: usize,
+    ) -> impl Iterator<Item = Result<GlobalEntryRef, ClientError>> + '_ {
+        PageIter::new(0, move |page| {
+            let items = self.read_communications(by_dna.clone(), by_agent.clone(), count, page)?;
+            let next_page = next_numbered_page(&items, count, page);
+            Ok((items, next_page))
+        })
+    }
+
+    pub fn get_communication_methods_iter(
+        &self,
+        count: usize,
+    ) -> impl Iterator<Item = Result<DnaHash, ClientError>> + '_ {
+        PageIter::new(0, move |page| {
+            let items = self.get_communication_methods(count, page)?;
+            let next_page = next_numbered_page(&items, count, page);
+            Ok((items, next_page))
+        })
+    }
+
+    /// Yields nothing when the context does not keep a list of members
+    pub fn members_iter(
+        &self,
+        count: usize,
+    ) -> impl Iterator<Item = Result<Identity, ClientError>> + '_ {
+        PageIter::new(0, move |page| {
+            let items = self.members(count, page)?.unwrap_or_default();
+            let next_page = next_numbered_page(&items, count, page);
+            Ok((items, next_page))
+        })
+    }
+}
+
+impl<C: ZomeCaller> ExpressionClient<C> {
+    pub fn get_by_author_iter(
+        &self,
+        author: Identity,
+        filter: ExpressionFilter,
+        page_size: usize,
+    ) -> impl Iterator<Item = Result<Expression, ClientError>> + '_ {
+        PageIter::new(None, move |cursor| {
+            let page = self.get_by_author(author.clone(), filter.clone(), page_size, cursor)?;
+            Ok((page.items, page.next_cursor.map(Some)))
+        })
+    }
+
+    pub fn inbox_iter(
+        &self,
+        from: Option<Identity>,
+        page_size: usize,
+    ) -> impl Iterator<Item = Result<Expression, ClientError>> + '_ {
+        PageIter::new(None, move |cursor| {
+            let page = self.inbox_paged(from.clone(), page_size, cursor)?;
+            Ok((page.items, page.next_cursor.map(Some)))
+        })
+    }
+
+    pub fn boosts_of_iter(
+        &self,
+        expression: GlobalEntryRef,
+        count: usize,
+    ) -> impl Iterator<Item = Result<Expression, ClientError>> + '_ {
+        PageIter::new(0, move |page| {
+            let items = self.boosts_of(expression.clone(), count, page)?;
+            let next_page = next_numbered_page(&items, count, page);
+            Ok((items, next_page))
+        })
+    }
+
+    pub fn quotes_of_iter(
+        &self,
+        expression: GlobalEntryRef,
+        count: usize,
+    ) -> impl Iterator<Item = Result<Expression, ClientError>> + '_ {
+        PageIter::new(0, move |page| {
+            let items = self.quotes_of(expression.clone(), count, page)?;
+            let next_page = next_numbered_page(&items, count, page);
+            Ok((items, next_page))
+        })
+    }
+}
+
+impl<C: ZomeCaller> InterDnaClient<C> {
+    pub fn get_outgoing_iter(
+        &self,
+        source: GlobalEntryRef,
+        link_type: Option<LinkType>,
+        count: usize,
+    ) -> impl Iterator<Item = Result<GlobalEntryRef, ClientError>> + '_ {
+        PageIter::new(0, move |page| {
+            let items = self.get_outgoing(source.clone(), link_type.clone(), count, page)?;
+            let next_page = next_numbered_page(&items, count, page);
+            Ok((items, next_page))
+        })
+    }
+
+    pub fn get_incoming_iter(
+        &self,
+        target: GlobalEntryRef,
+        link_type: Option<LinkType>,
+        count: usize,
+    ) -> impl Iterator<Item = Result<GlobalEntryRef, ClientError>> + '_ {
+        PageIter::new(0, move |page| {
+            let items = self.get_incoming(target.clone(), link_type.clone(), count, page)?;
+            let next_page = next_numbered_page(&items, count, page);
+            Ok((items, next_page))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Conductor errors carry the zome's error as Debug text inside their own Debug output
+    #[derive(Debug)]
+    struct RibosomeError(String);
+
+    #[test]
+    fn conductor_errors_decode_trait_errors() {
+        let error = TraitError::InvalidInput(String::from("\"x y\" is not a tag"));
+        let conductor_error = RibosomeError(format!("{:?}", HdkError::from(error.clone())));
+        assert_eq!(
+            ClientError::from_conductor_error(conductor_error),
+            ClientError::Trait(error)
+        );
+    }
+
+    #[test]
+    fn other_conductor_errors_are_kept() {
+        assert_eq!(
+            ClientError::from_conductor_error(RibosomeError(String::from("zome not found"))),
+            ClientError::Conductor(String::from("RibosomeError(\"zome not found\")"))
+        );
+    }
+
+    #[test]
+    fn page_iter_ends_at_empty_page() {
+        let mut fetches = 0;
+        let items: Vec<_> = PageIter::new(0, |page: usize| {
+            fetches += 1;
+            let items = if page == 0 { vec![1, 2] } else { Vec::new() };
+            Ok((items, Some(page + 1)))
+        })
+        .collect();
+        assert_eq!(items, vec![Ok(1), Ok(2)]);
+        assert_eq!(fetches, 2);
+    }
+
+    #[test]
+    fn page_iter_ends_at_repeated_cursor() {
+        let items: Vec<_> = PageIter::new(None, |cursor: Option<String>| {
+            let item = if cursor.is_none() { "first" } else { "second" };
+            Ok((vec![item], Some(Some(String::from("1")))))
+        })
+        .collect();
+        assert_eq!(items, vec![Ok("first"), Ok("second")]);
+    }
+}
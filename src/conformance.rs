@@ -0,0 +1,324 @@
+//! Executable definition of what it means to implement the traits in this crate. Each function exercises an
+//! implementation and reports the first check it fails, so DNA authors can run them from whichever test
+//! harness they use.
+//!
+//! The traits have no notion of which agent is calling, so functions taking `act_as` call it to switch the
+//! agent subsequent calls are made as; e.g. `reference::set_current_agent` for the reference implementations.
+use hdk3::prelude::*;
+
+use crate::{
+    Audience, ExpressionDao, GlobalEntryRef, Identity, InterDNADao, LinkType, SocialGraphDao,
+};
+
+/// Check an implementation failed
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConformanceFailure {
+    pub check: &'static str,
+    pub detail: String,
+}
+
+pub type ConformanceResult = Result<(), ConformanceFailure>;
+
+fn check(passed: bool, check: &'static str, detail: &str) -> ConformanceResult {
+    if passed {
+        Ok(())
+    } else {
+        Err(ConformanceFailure {
+            check,
+            detail: String::from(detail),
+        })
+    }
+}
+
+fn call<T>(check: &'static str, result: ExternResult<T>) -> Result<T, ConformanceFailure> {
+    result.map_err(|err| ConformanceFailure {
+        check,
+        detail: format!("call failed: {:?}", err),
+    })
+}
+
+/// Follow/unfollow symmetry, nth level traversal and the friendship request handshake. Unfollowing with a
+/// `by` of None removes follows made with any `by`.
+/// agents must be three distinct agents with no existing relationships
+pub fn social_graph_conformance<T, F>(agents: &[Identity; 3], act_as: F) -> ConformanceResult
+where
+    T: SocialGraphDao,
+    F: Fn(&Identity),
+{
+    let [alice, bob, carol] = agents;
+
+    act_as(alice);
+    call("follow", T::follow(bob.clone(), None))?;
+    check(
+        call("followers", T::followers(bob.clone(), None))?.contains(alice),
+        "follow symmetry",
+        "followed agent's followers do not include follower",
+    )?;
+    check(
+        call("my_followings", T::my_followings(None))?.contains(bob),
+        "follow symmetry",
+        "follower's followings do not include followed agent",
+    )?;
+    act_as(bob);
+    check(
+        call("my_followers", T::my_followers(None))?.contains(alice),
+        "follow symmetry",
+        "my_followers of followed agent does not include follower",
+    )?;
+
+    act_as(carol);
+    call("follow", T::follow(alice.clone(), None))?;
+    check(
+        call(
+            "nth_level_followers",
+            T::nth_level_followers(2, bob.clone(), None),
+        )?
+        .contains(carol),
+        "nth level followers",
+        "follower of a follower is not a 2nd level follower",
+    )?;
+    check(
+        call(
+            "nth_level_following",
+            T::nth_level_following(2, carol.clone(), None),
+        )?
+        .contains(bob),
+        "nth level following",
+        "followed agent of a followed agent is not 2nd level following",
+    )?;
+    call("unfollow", T::unfollow(alice.clone(), None))?;
+
+    act_as(alice);
+    call("unfollow", T::unfollow(bob.clone(), None))?;
+    check(
+        !call("followers", T::followers(bob.clone(), None))?.contains(alice),
+        "unfollow symmetry",
+        "unfollowed agent's followers still include follower",
+    )?;
+    check(
+        !call("following", T::following(alice.clone(), None))?.contains(bob),
+        "unfollow symmetry",
+        "unfollowing agent still follows unfollowed agent",
+    )?;
+    call("follow", T::follow(bob.clone(), Some(String::from("friends"))))?;
+    call("unfollow", T::unfollow(bob.clone(), None))?;
+    check(
+        !call("following", T::following(alice.clone(), None))?.contains(bob),
+        "unfollow symmetry",
+        "unfollow without by did not remove a follow made with by",
+    )?;
+
+    call("request_friendship", T::request_friendship(bob.clone()))?;
+    check(
+        call(
+            "outgoing_friendship_requests",
+            T::outgoing_friendship_requests(),
+        )?
+        .contains(bob),
+        "friendship handshake",
+        "request is not in requester's outgoing requests",
+    )?;
+    act_as(bob);
+    check(
+        call(
+            "incoming_friendship_requests",
+            T::incoming_friendship_requests(),
+        )?
+        .contains(alice),
+        "friendship handshake",
+        "request is not in requested agent's incoming requests",
+    )?;
+    call("request_friendship", T::request_friendship(alice.clone()))?;
+    check(
+        call("my_friends", T::my_friends())?.contains(alice)
+            && call("friends_of", T::friends_of(alice.clone()))?.contains(bob),
+        "friendship handshake",
+        "reciprocal requests did not make agents friends of each other",
+    )?;
+    check(
+        !call(
+            "incoming_friendship_requests",
+            T::incoming_friendship_requests(),
+        )?
+        .contains(alice),
+        "friendship handshake",
+        "accepted request is still pending",
+    )?;
+    call("drop_friendship", T::drop_friendship(alice.clone()))?;
+    check(
+        !call("friends_of", T::friends_of(alice.clone()))?.contains(bob),
+        "drop friendship",
+        "dropped friendship still present",
+    )?;
+
+    act_as(carol);
+    call("request_friendship", T::request_friendship(bob.clone()))?;
+    act_as(bob);
+    call("decline_friendship", T::decline_friendship(carol.clone()))?;
+    check(
+        !call(
+            "incoming_friendship_requests",
+            T::incoming_friendship_requests(),
+        )?
+        .contains(carol)
+            && !call("my_friends", T::my_friends())?.contains(carol),
+        "decline friendship",
+        "declined request is still pending or made agents friends",
+    )
+}
+
+/// Pages of outgoing links are disjoint, cover every link and agree with count_outgoing.
+/// targets must be distinct and source must have no existing links
+pub fn inter_dna_pagination_conformance<T: InterDNADao>(
+    source: GlobalEntryRef,
+    targets: Vec<GlobalEntryRef>,
+    page_size: usize,
+) -> ConformanceResult {
+    for target in targets.iter() {
+        call(
+            "create_link",
+            T::create_link(
+                source.clone(),
+                target.clone(),
+                LinkType::Reference,
+                None,
+                None,
+            ),
+        )?;
+    }
+    check(
+        call("count_outgoing", T::count_outgoing(source.clone(), None))? == targets.len(),
+        "count matches links",
+        "count_outgoing differs from number of links created",
+    )?;
+
+    let mut seen: Vec<GlobalEntryRef> = Vec::new();
+    let mut page = 0;
+    loop {
+        let items = call(
+            "get_outgoing",
+            T::get_outgoing(source.clone(), None, page_size, page),
+        )?;
+        check(
+            items.len() <= page_size,
+            "page size respected",
+            "get_outgoing returned more items than requested",
+        )?;
+        if items.is_empty() {
+            break;
+        }
+        for item in items {
+            check(
+                !seen.contains(&item),
+                "pages disjoint",
+                "item returned on more than one page",
+            )?;
+            seen.push(item);
+        }
+        page += 1;
+    }
+    check(
+        seen.len() == targets.len() && targets.iter().all(|target| seen.contains(target)),
+        "pages cover collection",
+        "paging through get_outgoing did not return every link",
+    )
+}
+
+/// A deleted expression is reported deleted, can no longer be fetched and has a tombstone.
+/// since must be no later than the current time of the implementation; i.e from sys_time when run in wasm,
+/// where chrono::Utc::now is unavailable
+pub fn expression_tombstone_conformance<T: ExpressionDao>(
+    content: String,
+    since: chrono::DateTime<chrono::Utc>,
+) -> ConformanceResult {
+    let expression = call(
+        "create_public_expression",
+        T::create_public_expression(content, Audience::Public),
+    )?;
+    let address = AnyDhtHash::from(expression.expression.header_address().clone());
+
+    check(
+        !call("is_deleted", T::is_deleted(address.clone()))?,
+        "tombstone",
+        "new expression reported as deleted",
+    )?;
+    let tombstone = call("delete_expression", T::delete_expression(address.clone()))?;
+    check(
+        tombstone.expression == address,
+        "tombstone",
+        "tombstone does not reference deleted expression",
+    )?;
+    check(
+        call("is_deleted", T::is_deleted(address.clone()))?,
+        "tombstone",
+        "deleted expression not reported as deleted",
+    )?;
+    check(
+        call(
+            "get_expression_by_address",
+            T::get_expression_by_address(address.clone()),
+        )?
+        .is_none(),
+        "tombstone",
+        "deleted expression can still be fetched",
+    )?;
+    check(
+        call("tombstones_since", T::tombstones_since(since))?
+            .iter()
+            .any(|tombstone| tombstone.expression == address),
+        "tombstone",
+        "tombstones_since does not include deletion",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reference::{self, InMemoryExpression, InMemoryInterDna, InMemorySocialGraph};
+    use holo_hash::DnaHash;
+
+    fn agent(seed: u8) -> Identity {
+        Identity::from_raw_bytes(vec![seed; 36])
+    }
+
+    fn entry(dna: u8, entry: u8) -> GlobalEntryRef {
+        GlobalEntryRef {
+            dna: DnaHash::from_raw_bytes(vec![dna; 36]),
+            entry_address: HeaderHash::from_raw_bytes(vec![entry; 36]),
+        }
+    }
+
+    #[test]
+    fn reference_social_graph_conforms() {
+        reference::reset();
+        let agents = [agent(1), agent(2), agent(3)];
+        assert_eq!(
+            social_graph_conformance::<InMemorySocialGraph, _>(&agents, |agent| {
+                reference::set_current_agent(agent.clone())
+            }),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn reference_inter_dna_pagination_conforms() {
+        reference::reset();
+        reference::set_current_agent(agent(1));
+        let targets = (1..=7).map(|seed| entry(2, seed)).collect();
+        assert_eq!(
+            inter_dna_pagination_conformance::<InMemoryInterDna>(entry(1, 0), targets, 3),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn reference_expression_tombstones_conform() {
+        reference::reset();
+        reference::set_current_agent(agent(1));
+        let since = chrono::DateTime::from_timestamp(1_600_000_000, 0).unwrap();
+        assert_eq!(
+            expression_tombstone_conformance::<InMemoryExpression>(String::from("hello"), since),
+            Ok(())
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! Rules for recognising expressions which share the same content across DNAs; i.e several link posts of one article,
+//! so that they can be collapsed into a single discussion. Every DNA must derive content hashes with these functions
+//! for lookups by hash to work across DNAs.
+use hdk3::prelude::*;
+
+use crate::canonical::canonical_hash;
+use crate::errors::TraitError;
+use crate::GlobalEntryRef;
+
+/// Query parameters which only track where a link was shared from
+const TRACKING_PARAMETERS: &[&str] = &["fbclid", "gclid", "igshid", "mc_cid", "mc_eid", "ref_src"];
+
+fn is_tracking_parameter(parameter: &str) -> bool {
+    let name = parameter.split('=').next().unwrap_or_default();
+    name.starts_with("utm_") || TRACKING_PARAMETERS.contains(&name)
+}
+
+/// Normalize an http(s) url so that links to the same resource compare equal. The scheme becomes https, host is
+/// lowercased without "www." or a default port, the fragment, tracking parameters and any trailing '/' are removed
+/// and remaining query parameters are sorted
+pub fn normalize_url(url: &str) -> Result<String, TraitError> {
+    let invalid = || TraitError::InvalidInput(format!("{} is not an http(s) url", url));
+    let (scheme, rest) = url.trim().split_once("://").ok_or_else(invalid)?;
+    let scheme = scheme.to_lowercase();
+    if scheme != "http" && scheme != "https" {
+        return Err(invalid());
+    }
+    let rest = rest.split('#').next().unwrap_or_default();
+    let (authority, path_and_query) = match rest.find(|c| c == '/' || c == '?') {
+        Some(index) => rest.split_at(index),
+        None => (rest, ""),
+    };
+    let (path, query) = match path_and_query.split_once('?') {
+        Some((path, query)) => (path, query),
+        None => (path_and_query, ""),
+    };
+
+    let mut host = authority.to_lowercase();
+    let default_port = if scheme == "http" { ":80" } else { ":443" };
+    if host.ends_with(default_port) {
+        host.truncate(host.len() - default_port.len());
+    }
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+    if host.is_empty() {
+        return Err(invalid());
+    }
+
+    let path = path.trim_end_matches('/');
+    let mut parameters: Vec<&str> = query
+        .split('&')
+        .filter(|parameter| !parameter.is_empty() && !is_tracking_parameter(parameter))
+        .collect();
+    parameters.sort_unstable();
+
+    let mut normalized = format!("https://{}{}", host, path);
+    if !parameters.is_empty() {
+        normalized.push('?');
+        normalized.push_str(&parameters.join("&"));
+    }
+    Ok(normalized)
+}
+
+/// Content hash of an expression linking to url
+pub fn url_content_hash(url: &str) -> Result<EntryHash, TraitError> {
+    canonical_hash(&normalize_url(url)?)
+}
+
+/// Content hash of an expression's text, ignoring differences in case and whitespace
+pub fn text_content_hash(text: &str) -> Result<EntryHash, TraitError> {
+    let normalized: Vec<String> = text
+        .split_whitespace()
+        .map(|word| word.to_lowercase())
+        .collect();
+    canonical_hash(&normalized.join(" "))
+}
+
+/// Interface for finding expressions in a DNA with the same content as one elsewhere
+pub trait DedupDao {
+    /// Get expressions whose url_content_hash or text_content_hash is hash, oldest first
+    fn find_by_content_hash(hash: EntryHash) -> ExternResult<Vec<GlobalEntryRef>>;
+    /// Get the expression discussion of url should be collapsed into; the oldest expression linking to it
+    fn canonical_for(url: String) -> ExternResult<Option<GlobalEntryRef>>;
+}
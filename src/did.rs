@@ -0,0 +1,146 @@
+use hdk3::prelude::*;
+
+use crate::errors::TraitError;
+use crate::Identity;
+
+pub const DID_HOLO_METHOD: &str = "holo";
+pub const DID_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+
+/// Decentralized identifier of the form `did:<method>:<method specific id>`.
+/// Holochain agents use the `holo` method with their agent key as the method specific id
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(try_from = "String", into = "String")]
+pub struct Did(String);
+
+impl Did {
+    pub fn new(raw: &str) -> Result<Did, TraitError> {
+        use std::convert::TryFrom;
+        let invalid = || TraitError::InvalidInput(format!("{} is not a valid DID", raw));
+        let mut parts = raw.splitn(3, ':');
+        let (scheme, method, id) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(scheme), Some(method), Some(id)) => (scheme, method, id),
+            _ => return Err(invalid()),
+        };
+        if scheme != "did"
+            || method.is_empty()
+            || !method
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            || id.is_empty()
+            || !id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || ".-_:%".contains(c))
+        {
+            return Err(invalid());
+        }
+        if method == DID_HOLO_METHOD && Identity::try_from(id).is_err() {
+            return Err(invalid());
+        }
+        Ok(Did(String::from(raw)))
+    }
+
+    /// `did:holo:` identifier of agent
+    pub fn holo(agent: &Identity) -> Did {
+        Did(format!("did:{}:{}", DID_HOLO_METHOD, agent))
+    }
+
+    pub fn method(&self) -> &str {
+        self.0.split(':').nth(1).unwrap_or("")
+    }
+
+    pub fn method_specific_id(&self) -> &str {
+        self.0.splitn(3, ':').nth(2).unwrap_or("")
+    }
+
+    /// Agent identified by a `did:holo:` DID; None for other methods
+    pub fn holo_agent(&self) -> Option<Identity> {
+        use std::convert::TryFrom;
+        if self.method() == DID_HOLO_METHOD {
+            Identity::try_from(self.method_specific_id()).ok()
+        } else {
+            None
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::convert::TryFrom<String> for Did {
+    type Error = TraitError;
+
+    fn try_from(raw: String) -> Result<Self, Self::Error> {
+        Did::new(&raw)
+    }
+}
+
+impl From<Did> for String {
+    fn from(did: Did) -> String {
+        did.0
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationMethod {
+    /// Usually the DID with a key fragment; i.e `did:holo:<agent>#key-1`
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub controller: Did,
+    pub public_key_multibase: String,
+}
+
+/// W3C DID document resolving a DID to its keys
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocument {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    pub id: Did,
+    pub verification_method: Vec<VerificationMethod>,
+    /// Ids of verification_method entries which can authenticate as the DID subject
+    pub authentication: Vec<String>,
+    /// Other DIDs linked to this one via DecentralizedIdentityDao::link_did
+    #[serde(default)]
+    pub also_known_as: Vec<String>,
+}
+
+/// Interface allowing Holochain agents to be referenced from DID aware systems
+pub trait DecentralizedIdentityDao {
+    /// `did:holo:` DID of current agent
+    fn did() -> ExternResult<Did>;
+    fn did_document() -> ExternResult<DidDocument>;
+    /// Resolve DID document of another agent's `did:holo:` DID
+    fn resolve(did: Did) -> ExternResult<Option<DidDocument>>;
+    /// Link an external DID to current agent. proof is a signature by the external DID's key over current agent's DID
+    fn link_did(external_did: Did, proof: Vec<u8>) -> ExternResult<()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn holo_did_of_agent_parses() {
+        let agent = Identity::from_raw_bytes(vec![1; 36]);
+        let did = Did::new(Did::holo(&agent).as_str()).unwrap();
+        assert_eq!(did.holo_agent(), Some(agent));
+    }
+
+    #[test]
+    fn holo_did_requires_agent_key() {
+        assert!(Did::new("did:holo:notanagentkey").is_err());
+        let dna = holo_hash::DnaHash::from_raw_bytes(vec![1; 36]);
+        assert!(Did::new(&format!("did:holo:{}", dna)).is_err());
+    }
+
+    #[test]
+    fn other_methods_are_not_checked_as_agent_keys() {
+        assert!(Did::new("did:web:example.com").is_ok());
+    }
+}
@@ -0,0 +1,269 @@
+use hdk3::prelude::*;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Standardized failures which implementations of the traits in this crate can surface to callers.
+/// Each variant maps to a stable code so that clients can render a message without parsing free text.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum TraitError {
+    NotFound,
+    Unauthorized,
+    InvalidInput(String),
+    RateLimited,
+    Unsupported,
+    Internal(String),
+}
+
+impl TraitError {
+    /// Stable, locale independent code for this error
+    pub fn code(&self) -> &'static str {
+        match self {
+            TraitError::NotFound => "not_found",
+            TraitError::Unauthorized => "unauthorized",
+            TraitError::InvalidInput(_) => "invalid_input",
+            TraitError::RateLimited => "rate_limited",
+            TraitError::Unsupported => "unsupported",
+            TraitError::Internal(_) => "internal",
+        }
+    }
+
+    /// Free text detail carried by the error, substituted for `{detail}` in message templates
+    pub fn detail(&self) -> Option<&str> {
+        match self {
+            TraitError::InvalidInput(detail) | TraitError::Internal(detail) => Some(detail),
+            _ => None,
+        }
+    }
+}
+
+/// Marker preceding the encoded error in the message of a WasmError::Zome built from a TraitError
+const ZOME_ERROR_MARKER: &str = "trait_error:";
+
+/// Levels of Debug output wrapping a zome error message from_zome_error sees through
+const MAX_ESCAPE_DEPTH: usize = 4;
+
+/// Form a TraitError takes inside a zome error message
+#[derive(Serialize, Deserialize)]
+struct EncodedTraitError {
+    code: String,
+    detail: Option<String>,
+}
+
+impl TraitError {
+    /// Error for a code returned by code(); None for unknown codes
+    pub fn from_code(code: &str, detail: Option<String>) -> Option<TraitError> {
+        let detail = || detail.clone().unwrap_or_default();
+        match code {
+            "not_found" => Some(TraitError::NotFound),
+            "unauthorized" => Some(TraitError::Unauthorized),
+            "invalid_input" => Some(TraitError::InvalidInput(detail())),
+            "rate_limited" => Some(TraitError::RateLimited),
+            "unsupported" => Some(TraitError::Unsupported),
+            "internal" => Some(TraitError::Internal(detail())),
+            _ => None,
+        }
+    }
+
+    /// Message of the WasmError::Zome this error is returned from a zome function as
+    pub fn to_zome_error(&self) -> String {
+        let encoded = EncodedTraitError {
+            code: String::from(self.code()),
+            detail: self.detail().map(String::from),
+        };
+        format!(
+            "{}{}",
+            ZOME_ERROR_MARKER,
+            serde_json::to_string(&encoded).unwrap_or_default()
+        )
+    }
+
+    /// Recover the error a zome function failed with from the error message a caller received. The message may
+    /// wrap the zome error, i.e in the Debug output of the conductor's error, with its quotes escaped once per
+    /// level of wrapping. None if the zome function did not fail with a TraitError
+    pub fn from_zome_error(message: &str) -> Option<TraitError> {
+        let start = message.find(ZOME_ERROR_MARKER)? + ZOME_ERROR_MARKER.len();
+        let mut encoded = String::from(&message[start..]);
+        for _ in 0..MAX_ESCAPE_DEPTH {
+            let decoded = serde_json::Deserializer::from_str(&encoded)
+                .into_iter::<EncodedTraitError>()
+                .next()
+                .and_then(Result::ok);
+            if let Some(decoded) = decoded {
+                return TraitError::from_code(&decoded.code, decoded.detail);
+            }
+            encoded = unescape(&encoded);
+        }
+        None
+    }
+}
+
+/// Undo one level of Debug string escaping
+fn unescape(escaped: &str) -> String {
+    let mut unescaped = String::with_capacity(escaped.len());
+    let mut chars = escaped.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n') => unescaped.push('\n'),
+                Some('t') => unescaped.push('\t'),
+                Some('r') => unescaped.push('\r'),
+                Some(escaped) => unescaped.push(escaped),
+                None => {}
+            },
+            c => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+impl From<TraitError> for WasmError {
+    fn from(error: TraitError) -> WasmError {
+        WasmError::Zome(error.to_zome_error())
+    }
+}
+
+impl From<TraitError> for HdkError {
+    fn from(error: TraitError) -> HdkError {
+        HdkError::Wasm(WasmError::from(error))
+    }
+}
+
+impl fmt::Display for TraitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.detail() {
+            Some(detail) => write!(f, "{}: {}", self.code(), detail),
+            None => write!(f, "{}", self.code()),
+        }
+    }
+}
+
+pub const DEFAULT_LOCALE: &str = "en";
+
+const EN: &[(&str, &str)] = &[
+    ("not_found", "The requested item could not be found."),
+    ("unauthorized", "You are not allowed to perform this action."),
+    ("invalid_input", "The request was invalid: {detail}"),
+    ("rate_limited", "Too many requests; please try again later."),
+    ("unsupported", "This operation is not supported by this DNA."),
+    ("internal", "Something went wrong: {detail}"),
+];
+
+const ES: &[(&str, &str)] = &[
+    ("not_found", "No se pudo encontrar el elemento solicitado."),
+    ("unauthorized", "No tienes permiso para realizar esta acción."),
+    ("invalid_input", "La solicitud no es válida: {detail}"),
+    ("rate_limited", "Demasiadas solicitudes; inténtalo de nuevo más tarde."),
+    ("unsupported", "Esta operación no es compatible con este DNA."),
+    ("internal", "Algo salió mal: {detail}"),
+];
+
+const DE: &[(&str, &str)] = &[
+    ("not_found", "Das angeforderte Element wurde nicht gefunden."),
+    ("unauthorized", "Du bist nicht berechtigt, diese Aktion auszuführen."),
+    ("invalid_input", "Die Anfrage ist ungültig: {detail}"),
+    ("rate_limited", "Zu viele Anfragen; bitte versuche es später erneut."),
+    ("unsupported", "Dieser Vorgang wird von dieser DNA nicht unterstützt."),
+    ("internal", "Etwas ist schiefgelaufen: {detail}"),
+];
+
+/// Message templates for TraitError codes in a given locale.
+/// Codes missing from a locale fall back to the english template.
+#[derive(Debug, Clone)]
+pub struct ErrorMessageCatalog {
+    pub locale: String,
+    templates: HashMap<String, String>,
+}
+
+impl ErrorMessageCatalog {
+    /// Locales which have templates shipped in this crate
+    pub fn shipped_locales() -> Vec<&'static str> {
+        vec!["en", "es", "de"]
+    }
+
+    /// Catalog for a BCP-47 style locale; "es-MX" resolves to "es". Unknown locales get the english catalog
+    pub fn for_locale(locale: &str) -> Self {
+        let language = locale.split(|c| c == '-' || c == '_').next().unwrap_or(DEFAULT_LOCALE);
+        let (locale, templates) = match language.to_lowercase().as_str() {
+            "es" => ("es", ES),
+            "de" => ("de", DE),
+            _ => (DEFAULT_LOCALE, EN),
+        };
+        ErrorMessageCatalog {
+            locale: String::from(locale),
+            templates: templates
+                .iter()
+                .map(|(code, template)| (String::from(*code), String::from(*template)))
+                .collect(),
+        }
+    }
+
+    /// Override or add the template used for an error code
+    pub fn with_template(mut self, code: &str, template: &str) -> Self {
+        self.templates.insert(String::from(code), String::from(template));
+        self
+    }
+
+    pub fn template(&self, code: &str) -> Option<&str> {
+        self.templates.get(code).map(|template| template.as_str()).or_else(|| {
+            EN.iter()
+                .find(|(en_code, _)| *en_code == code)
+                .map(|(_, template)| *template)
+        })
+    }
+
+    /// Render a human readable message for the error in this catalog's locale
+    pub fn render(&self, error: &TraitError) -> String {
+        let template = self.template(error.code()).unwrap_or("{code}");
+        template
+            .replace("{code}", error.code())
+            .replace("{detail}", error.detail().unwrap_or(""))
+    }
+}
+
+impl Default for ErrorMessageCatalog {
+    fn default() -> Self {
+        ErrorMessageCatalog::for_locale(DEFAULT_LOCALE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn errors() -> Vec<TraitError> {
+        vec![
+            TraitError::NotFound,
+            TraitError::Unauthorized,
+            TraitError::InvalidInput(String::from("tag \"x y\" contains whitespace")),
+            TraitError::RateLimited,
+            TraitError::Unsupported,
+            TraitError::Internal(String::from("line one\nline two")),
+        ]
+    }
+
+    #[test]
+    fn zome_error_round_trips() {
+        for error in errors() {
+            match HdkError::from(error.clone()) {
+                HdkError::Wasm(WasmError::Zome(message)) => {
+                    assert_eq!(TraitError::from_zome_error(&message), Some(error))
+                }
+                other => panic!("unexpected error {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn zome_error_round_trips_through_debug_output() {
+        for error in errors() {
+            let message = format!("{:?}", HdkError::from(error.clone()));
+            assert_eq!(TraitError::from_zome_error(&message), Some(error));
+        }
+    }
+
+    #[test]
+    fn other_zome_errors_are_not_decoded() {
+        assert_eq!(TraitError::from_zome_error("Zome(\"entry not found\")"), None);
+    }
+}
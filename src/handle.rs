@@ -0,0 +1,92 @@
+use hdk3::prelude::*;
+use holo_hash::DnaHash;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use crate::errors::TraitError;
+use crate::Identity;
+
+/// Agent optionally qualified by the social context DNA it is being referenced in
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Handle {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub agent: Identity,
+    #[cfg_attr(feature = "schema", schemars(with = "Option<crate::schema::HoloHash>"))]
+    pub context: Option<DnaHash>,
+}
+
+/// Rules for rendering and parsing agent handles so that every client displays identities identically.
+///
+/// Canonical handles are `@<agent>` or `@<agent>@<context dna>` using the base64 string forms of the hashes.
+/// Display handles use a petname if one is set for the agent, otherwise the agent key truncated to truncate_to characters.
+#[derive(Debug, Clone)]
+pub struct HandleFormat {
+    pub truncate_to: usize,
+    pub petnames: HashMap<Identity, String>,
+}
+
+impl Default for HandleFormat {
+    fn default() -> Self {
+        HandleFormat {
+            truncate_to: 8,
+            petnames: HashMap::new(),
+        }
+    }
+}
+
+impl HandleFormat {
+    pub fn with_petname(mut self, agent: Identity, petname: String) -> Self {
+        self.petnames.insert(agent, petname);
+        self
+    }
+
+    /// Canonical, parseable form of handle
+    pub fn canonical(handle: &Handle) -> String {
+        match &handle.context {
+            Some(context) => format!("@{}@{}", handle.agent, context),
+            None => format!("@{}", handle.agent),
+        }
+    }
+
+    /// Human friendly form of handle; not parseable
+    pub fn display(&self, handle: &Handle) -> String {
+        match self.petnames.get(&handle.agent) {
+            Some(petname) => petname.clone(),
+            None => {
+                let key = handle.agent.to_string();
+                let truncated: String = key.chars().take(self.truncate_to).collect();
+                if truncated.len() < key.len() {
+                    format!("@{}…", truncated)
+                } else {
+                    format!("@{}", truncated)
+                }
+            }
+        }
+    }
+
+    /// Parse canonical form of a handle
+    pub fn parse(handle: &str) -> Result<Handle, TraitError> {
+        let mut parts = handle
+            .strip_prefix('@')
+            .ok_or_else(|| TraitError::InvalidInput(String::from("handle must start with '@'")))?
+            .split('@');
+        let agent = parts
+            .next()
+            .and_then(|agent| Identity::try_from(agent).ok())
+            .ok_or_else(|| TraitError::InvalidInput(format!("invalid agent in handle {}", handle)))?;
+        let context = match parts.next() {
+            Some(context) => Some(DnaHash::try_from(context).map_err(|_| {
+                TraitError::InvalidInput(format!("invalid context in handle {}", handle))
+            })?),
+            None => None,
+        };
+        if parts.next().is_some() {
+            return Err(TraitError::InvalidInput(format!(
+                "handle {} has too many parts",
+                handle
+            )));
+        }
+        Ok(Handle { agent, context })
+    }
+}
@@ -0,0 +1,96 @@
+use hdk3::prelude::*;
+
+use crate::errors::TraitError;
+use crate::{ExpressionPage, Identity, Ordering, Tag};
+
+const BY_DAY: &str = "by_day";
+const BY_TAG: &str = "by_tag";
+const BY_AUTHOR: &str = "by_author";
+const DAY_FORMAT: &str = "%Y-%m-%d";
+
+/// Standard anchor under which expression DNAs link expressions for discovery, so that buckets are named the same
+/// across DNAs. Anchor strings are dot separated hdk Path components; i.e "by_day.2020-11-23", "by_tag.holochain"
+/// or "by_author.uhCAk..."
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum IndexPath {
+    /// Expressions created on a UTC day
+    Day(chrono::NaiveDate),
+    Tag(Tag),
+    Author(#[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))] Identity),
+}
+
+impl IndexPath {
+    pub fn day_of(timestamp: chrono::DateTime<chrono::Utc>) -> Self {
+        IndexPath::Day(timestamp.date_naive())
+    }
+
+    /// Day buckets covering start to end inclusive, in chronological order
+    pub fn days_between(
+        start: chrono::DateTime<chrono::Utc>,
+        end: chrono::DateTime<chrono::Utc>,
+    ) -> Vec<Self> {
+        let mut days = Vec::new();
+        let mut day = Some(start.date_naive());
+        while let Some(current) = day.filter(|current| *current <= end.date_naive()) {
+            days.push(IndexPath::Day(current));
+            day = current.succ_opt();
+        }
+        days
+    }
+
+    pub fn to_anchor_string(&self) -> String {
+        match self {
+            IndexPath::Day(day) => format!("{}.{}", BY_DAY, day.format(DAY_FORMAT)),
+            IndexPath::Tag(tag) => format!("{}.{}", BY_TAG, tag.as_str()),
+            IndexPath::Author(agent) => format!("{}.{}", BY_AUTHOR, agent),
+        }
+    }
+
+    pub fn to_path(&self) -> Path {
+        Path::from(self.to_anchor_string())
+    }
+}
+
+impl std::fmt::Display for IndexPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_anchor_string())
+    }
+}
+
+impl std::str::FromStr for IndexPath {
+    type Err = TraitError;
+
+    fn from_str(anchor: &str) -> Result<Self, Self::Err> {
+        let invalid = || TraitError::InvalidInput(format!("{} is not a valid index path", anchor));
+        let mut parts = anchor.splitn(2, '.');
+        let (scheme, value) = match (parts.next(), parts.next()) {
+            (Some(scheme), Some(value)) => (scheme, value),
+            _ => return Err(invalid()),
+        };
+        use std::convert::TryFrom;
+        match scheme {
+            BY_DAY => chrono::NaiveDate::parse_from_str(value, DAY_FORMAT)
+                .map(IndexPath::Day)
+                .map_err(|_| invalid()),
+            BY_TAG => Tag::new(value).map(IndexPath::Tag),
+            BY_AUTHOR => Identity::try_from(value)
+                .map(IndexPath::Author)
+                .map_err(|_| invalid()),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Interface for time bucketed discovery of expressions. Implementations should link expressions from the
+/// IndexPath::Day anchor of their creation time so that every expression DNA can be queried the same way
+pub trait TimeIndexDao {
+    /// Get expressions created between start and end inclusive
+    fn expressions_between(
+        start: chrono::DateTime<chrono::Utc>,
+        end: chrono::DateTime<chrono::Utc>,
+        page_size: usize,
+        cursor: Option<String>,
+        ordering: Ordering,
+    ) -> ExternResult<ExpressionPage>;
+}
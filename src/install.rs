@@ -0,0 +1,111 @@
+use hdk3::prelude::*;
+use holo_hash::DnaHash;
+
+/// Reference to a DNA bundle which a conductor should install
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DnaBundleRef {
+    /// Name used for the installed cell
+    pub name: String,
+    /// Location the bundle can be fetched from
+    pub url: Option<String>,
+    /// Expected hash of the DNA once installed, if known ahead of time
+    #[cfg_attr(feature = "schema", schemars(with = "Option<crate::schema::HoloHash>"))]
+    pub hash: Option<DnaHash>,
+}
+
+/// Another DNA which must be installed and bridged to for this DNA to function
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BridgeRequirement {
+    /// Handle the calling DNA uses to address the bridged DNA
+    pub handle: String,
+    pub dna: DnaBundleRef,
+    /// Whether the DNA can run without this bridge
+    pub required: bool,
+}
+
+/// Input an onboarding client should ask the user for to construct a membrane proof
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MembraneProofPrompt {
+    pub key: String,
+    pub label: String,
+    pub description: Option<String>,
+    pub required: bool,
+}
+
+/// Describes a DNA implementing some of this crate's traits along with what is needed to install it
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DnaDescriptor {
+    pub dna: DnaBundleRef,
+    /// Names of the traits from this crate the DNA implements; i.e "SocialGraphDao"
+    pub implements: Vec<String>,
+    pub bridges: Vec<BridgeRequirement>,
+    pub membrane_proof_prompts: Vec<MembraneProofPrompt>,
+}
+
+/// Single conductor admin operation within an InstallPlan
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum InstallStep {
+    InstallDna {
+        dna: DnaBundleRef,
+        membrane_proof_prompts: Vec<MembraneProofPrompt>,
+    },
+    ConnectBridge {
+        caller: String,
+        callee: String,
+        handle: String,
+        required: bool,
+    },
+    Activate {
+        name: String,
+    },
+}
+
+/// Ordered set of conductor admin operations needed to install a DNA described by a DnaDescriptor
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct InstallPlan {
+    pub steps: Vec<InstallStep>,
+}
+
+impl InstallPlan {
+    /// Generate plan for descriptor. Bridged DNAs are installed before the DNA which depends on them,
+    /// each DNA is only installed once and everything installed is activated once bridges are connected
+    pub fn generate(descriptor: &DnaDescriptor) -> Self {
+        let mut steps = Vec::new();
+        let mut installed: Vec<String> = Vec::new();
+
+        for bridge in descriptor.bridges.iter() {
+            if bridge.dna.name != descriptor.dna.name && !installed.contains(&bridge.dna.name) {
+                installed.push(bridge.dna.name.clone());
+                steps.push(InstallStep::InstallDna {
+                    dna: bridge.dna.clone(),
+                    membrane_proof_prompts: vec![],
+                });
+            }
+        }
+        installed.push(descriptor.dna.name.clone());
+        steps.push(InstallStep::InstallDna {
+            dna: descriptor.dna.clone(),
+            membrane_proof_prompts: descriptor.membrane_proof_prompts.clone(),
+        });
+
+        for bridge in descriptor.bridges.iter() {
+            steps.push(InstallStep::ConnectBridge {
+                caller: descriptor.dna.name.clone(),
+                callee: bridge.dna.name.clone(),
+                handle: bridge.handle.clone(),
+                required: bridge.required,
+            });
+        }
+        for name in installed {
+            steps.push(InstallStep::Activate { name });
+        }
+
+        InstallPlan { steps }
+    }
+}
@@ -0,0 +1,1039 @@
+//! Canonical input and output types of the zome functions for every trait method, so that every implementation
+//! of a trait shares the same wire format and callers in other languages can rely on it.
+//!
+//! For a method `follow(target_agent: Identity, by: Option<String>) -> ExternResult<()>` the zome function takes
+//! a `FollowInput { target_agent, by }` and returns `FollowOutput(())`. The zome functions `expose_zome` generates
+//! take and return these types and the clients in `client` send and receive them, so neither defines its own.
+//! DTOs for each trait are in a module named after the trait without its Dao suffix; i.e `inter_dna` for
+//! InterDNADao, which is where `expose_zome` looks for them.
+use hdk3::prelude::*;
+use holo_hash::DnaHash;
+
+use crate::activitypub::{
+    Activity, Delivery, DispatchRules, InboxResult, PublicKey, UndoTarget, WebFingerResource,
+};
+use crate::did::{Did, DidDocument};
+use crate::limits::DnaLimits;
+use crate::previews::UnfurlMetadata;
+use crate::registry::TraitDescriptor;
+use crate::{
+    AnonymousPolicy, Attachment, AttachmentLimits, Attendee, Attestation, Audience, BatchResult,
+    Capability, CapabilityGrant, CiphertextMeta, Claim, CoAuthorshipProposal, ConsentRecord,
+    ConsentScope, ContextInvite, Conversation, ConversationMessage, DeliveryStatus, Device, Draft,
+    DraftId, EncryptionScheme, EncryptionSession, Event, EventDetails, Expression,
+    ExpressionFilter, ExpressionPage, FeedFilter, FeedPage, FilterRule, FnRef, GlobalEntryRef,
+    Identity, InterDnaLink, Invite, KeyRotationEvent, LanguageTag, LinkType, LinksByDna,
+    MembraneProof, MembraneSpec, MirrorDescriptor, MirrorSegment, Nonce, NotificationItem,
+    Ordering, PendingMessage, Poll, PollResults, RateLimit, ReadToken, Report, ReportReason,
+    Resolution, Role, Room, RoomMessage, RsvpStatus, SealedExpression, SearchQuery,
+    SocialContextProfile, Status, Subscription, Tag, Tombstone, ValidationResult,
+};
+
+macro_rules! dtos {
+    ($($input:ident, $output:ident { $($(#[$attr:meta])* $param:ident: $ty:ty),* $(,)? } -> $(#[$out_attr:meta])* $out:ty;)*) => {
+        $(
+            #[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+            #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+            pub struct $input {
+                $($(#[$attr])* pub $param: $ty,)*
+            }
+
+            #[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+            #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+            pub struct $output($(#[$out_attr])* pub $out);
+        )*
+
+        #[cfg(feature = "schema")]
+        pub(crate) fn add_schemas(
+            schemas: &mut std::collections::HashMap<String, schemars::schema::RootSchema>,
+        ) {
+            let module = module_path!().split_once("::").map(|(_, module)| module).unwrap_or_default();
+            $(
+                schemas.insert(format!("{}::{}", module, stringify!($input)), schemars::schema_for!($input));
+                schemas.insert(format!("{}::{}", module, stringify!($output)), schemars::schema_for!($output));
+            )*
+        }
+    };
+}
+
+/// DTOs for SocialGraphDao
+pub mod social_graph {
+    use super::*;
+
+    dtos! {
+        MyFollowersInput, MyFollowersOutput { by: Option<String> } -> #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))] Vec<Identity>;
+        FollowersInput, FollowersOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            followed_agent: Identity,
+            by: Option<String>,
+        } -> #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))] Vec<Identity>;
+        NthLevelFollowersInput, NthLevelFollowersOutput {
+            n: usize,
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            followed_agent: Identity,
+            by: Option<String>,
+        } -> #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))] Vec<Identity>;
+        MyFollowingsInput, MyFollowingsOutput { by: Option<String> } -> #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))] Vec<Identity>;
+        FollowingInput, FollowingOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            following_agent: Identity,
+            by: Option<String>,
+        } -> #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))] Vec<Identity>;
+        NthLevelFollowingInput, NthLevelFollowingOutput {
+            n: usize,
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            following_agent: Identity,
+            by: Option<String>,
+        } -> #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))] Vec<Identity>;
+        FollowInput, FollowOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            target_agent: Identity,
+            by: Option<String>,
+        } -> ();
+        UnfollowInput, UnfollowOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            target_agent: Identity,
+            by: Option<String>,
+        } -> ();
+        MyFriendsInput, MyFriendsOutput {} -> #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))] Vec<Identity>;
+        FriendsOfInput, FriendsOfOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            agent: Identity,
+        } -> #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))] Vec<Identity>;
+        RequestFriendshipInput, RequestFriendshipOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            target_agent: Identity,
+        } -> ();
+        DeclineFriendshipInput, DeclineFriendshipOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            target_agent: Identity,
+        } -> ();
+        IncomingFriendshipRequestsInput, IncomingFriendshipRequestsOutput {} -> #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))] Vec<Identity>;
+        OutgoingFriendshipRequestsInput, OutgoingFriendshipRequestsOutput {} -> #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))] Vec<Identity>;
+        DropFriendshipInput, DropFriendshipOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            target_agent: Identity,
+        } -> ();
+    }
+}
+
+/// DTOs for SocialContextDao
+pub mod social_context {
+    use super::*;
+
+    dtos! {
+        PostInput, PostOutput { expression_ref: GlobalEntryRef } -> ();
+        RegisterCommunicationMethodInput, RegisterCommunicationMethodOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            dna_address: DnaHash,
+        } -> ();
+        WritableInput, WritableOutput {} -> bool;
+        ReadCommunicationsInput, ReadCommunicationsOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "Option<crate::schema::HoloHash>"))]
+            by_dna: Option<DnaHash>,
+            #[cfg_attr(feature = "schema", schemars(with = "Option<crate::schema::HoloHash>"))]
+            by_agent: Option<Identity>,
+            count: usize,
+            page: usize,
+        } -> Vec<GlobalEntryRef>;
+        GetCommunicationMethodsInput, GetCommunicationMethodsOutput { count: usize, page: usize } -> #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))] Vec<DnaHash>;
+        MembersInput, MembersOutput { count: usize, page: usize } -> #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<crate::schema::HoloHash>>"))] Option<Vec<Identity>>;
+        JoinInput, JoinOutput {} -> ();
+        LeaveInput, LeaveOutput {} -> ();
+        IsMemberInput, IsMemberOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            agent: Identity,
+        } -> bool;
+        InviteInput, InviteOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            agent: Identity,
+        } -> ContextInvite;
+        AcceptInviteInput, AcceptInviteOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            invite: HeaderHash,
+        } -> ();
+        DeclineInviteInput, DeclineInviteOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            invite: HeaderHash,
+        } -> ();
+        PendingInvitesInput, PendingInvitesOutput {} -> Vec<ContextInvite>;
+        GetContextInfoInput, GetContextInfoOutput {} -> SocialContextProfile;
+        UpdateContextInfoInput, UpdateContextInfoOutput { profile: SocialContextProfile } -> SocialContextProfile;
+        PinExpressionInput, PinExpressionOutput { entry: GlobalEntryRef } -> ();
+        UnpinExpressionInput, UnpinExpressionOutput { entry: GlobalEntryRef } -> ();
+        PinnedInput, PinnedOutput {} -> Vec<GlobalEntryRef>;
+    }
+}
+
+/// DTOs for SocialContextPermissionsDao
+pub mod social_context_permissions {
+    use super::*;
+
+    dtos! {
+        AssignRoleInput, AssignRoleOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            agent: Identity,
+            role: Role,
+        } -> ();
+        RoleOfInput, RoleOfOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            agent: Identity,
+        } -> Option<Role>;
+        CanInput, CanOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            agent: Identity,
+            capability: Capability,
+        } -> bool;
+    }
+}
+
+/// DTOs for ExpressionDao
+pub mod expression {
+    use super::*;
+
+    dtos! {
+        CreatePublicExpressionInput, CreatePublicExpressionOutput { content: String, audience: Audience } -> Expression;
+        GetByAuthorInput, GetByAuthorOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            author: Identity,
+            filter: ExpressionFilter,
+            page_size: usize,
+            cursor: Option<String>,
+        } -> ExpressionPage;
+        GetExpressionByAddressInput, GetExpressionByAddressOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            address: AnyDhtHash,
+        } -> Option<Expression>;
+        GetManyByAddressInput, GetManyByAddressOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))]
+            addresses: Vec<AnyDhtHash>,
+        } -> Vec<Option<Expression>>;
+        DeleteExpressionInput, DeleteExpressionOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            address: AnyDhtHash,
+        } -> Tombstone;
+        IsDeletedInput, IsDeletedOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            address: AnyDhtHash,
+        } -> bool;
+        TombstonesSinceInput, TombstonesSinceOutput { timestamp: chrono::DateTime<chrono::Utc> } -> Vec<Tombstone>;
+        BoostInput, BoostOutput { expression: GlobalEntryRef } -> Expression;
+        QuoteInput, QuoteOutput { expression: GlobalEntryRef, commentary: String } -> Expression;
+        BoostsOfInput, BoostsOfOutput { expression: GlobalEntryRef, count: usize, page: usize } -> Vec<Expression>;
+        QuotesOfInput, QuotesOfOutput { expression: GlobalEntryRef, count: usize, page: usize } -> Vec<Expression>;
+        SendPrivateInput, SendPrivateOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            to: Identity,
+            content: String,
+        } -> DeliveryStatus;
+        PendingOutboxInput, PendingOutboxOutput {} -> Vec<PendingMessage>;
+        InboxInput, InboxOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "Option<crate::schema::HoloHash>"))]
+            from: Option<Identity>,
+            page_size: usize,
+            page_number: usize,
+        } -> Vec<Expression>;
+        InboxPagedInput, InboxPagedOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "Option<crate::schema::HoloHash>"))]
+            from: Option<Identity>,
+            page_size: usize,
+            cursor: Option<String>,
+        } -> ExpressionPage;
+        MarkReadInput, MarkReadOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            expression: AnyDhtHash,
+        } -> ();
+        UnreadCountInput, UnreadCountOutput {} -> usize;
+        DeleteFromInboxInput, DeleteFromInboxOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            expression: AnyDhtHash,
+        } -> ();
+    }
+}
+
+/// DTOs for InterDNADao
+pub mod inter_dna {
+    use super::*;
+
+    dtos! {
+        CreateLinkInput, CreateLinkOutput { source: GlobalEntryRef, target: GlobalEntryRef, link_type: LinkType, metadata: Option<String>, proof_of_work: Option<Nonce> } -> InterDnaLink;
+        CreateLinksInput, CreateLinksOutput { links: Vec<(GlobalEntryRef, GlobalEntryRef, LinkType, Option<Nonce>)> } -> BatchResult;
+        RemoveLinkInput, RemoveLinkOutput { source: GlobalEntryRef, target: GlobalEntryRef } -> InterDnaLink;
+        GetOutgoingInput, GetOutgoingOutput { source: GlobalEntryRef, link_type: Option<LinkType>, count: usize, page: usize } -> Vec<GlobalEntryRef>;
+        GetIncomingInput, GetIncomingOutput { target: GlobalEntryRef, link_type: Option<LinkType>, count: usize, page: usize } -> Vec<GlobalEntryRef>;
+        GetOutgoingMultiInput, GetOutgoingMultiOutput {
+            source: GlobalEntryRef,
+            #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))]
+            dnas: Vec<DnaHash>,
+            link_type: Option<LinkType>,
+            count: usize,
+            page: usize,
+        } -> Vec<LinksByDna>;
+        GetAllOutgoingInput, GetAllOutgoingOutput { source: GlobalEntryRef, count: usize, page: usize } -> Vec<LinksByDna>;
+        CountOutgoingInput, CountOutgoingOutput { source: GlobalEntryRef, link_type: Option<LinkType> } -> usize;
+        CountIncomingInput, CountIncomingOutput { target: GlobalEntryRef, link_type: Option<LinkType> } -> usize;
+        TranslationsOfInput, TranslationsOfOutput { expression: GlobalEntryRef } -> Vec<(LanguageTag, GlobalEntryRef)>;
+    }
+}
+
+/// DTOs for LinkPolicyDao
+pub mod link_policy {
+    use super::*;
+
+    dtos! {
+        ValidateLinkInput, ValidateLinkOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            author: Identity,
+            source: GlobalEntryRef,
+            target: GlobalEntryRef,
+            proof_of_work: Option<Nonce>,
+        } -> ValidationResult;
+        RateLimitInput, RateLimitOutput {} -> Option<RateLimit>;
+        RequiredWorkInput, RequiredWorkOutput {} -> Option<u32>;
+    }
+}
+
+/// DTOs for ReadTokenDao
+pub mod read_token {
+    use super::*;
+
+    dtos! {
+        IssueReadTokenInput, IssueReadTokenOutput {
+            collection: String,
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            audience: Identity,
+            expiry: Option<chrono::DateTime<chrono::Utc>>,
+        } -> ReadToken;
+        ReadWithTokenInput, ReadWithTokenOutput { collection: String, token: ReadToken, cursor: Option<String> } -> ExpressionPage;
+        RevokeReadTokenInput, RevokeReadTokenOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            token_address: HeaderHash,
+        } -> ();
+        IssuedReadTokensInput, IssuedReadTokensOutput { collection: String } -> Vec<ReadToken>;
+    }
+}
+
+/// DTOs for AttachmentsDao
+pub mod attachments {
+    use super::*;
+
+    dtos! {
+        UploadChunkedInput, UploadChunkedOutput { bytes: Vec<u8>, mime_type: String } -> Attachment;
+        GetAttachmentInput, GetAttachmentOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            hash: EntryHash,
+        } -> Option<Attachment>;
+        FetchAttachmentInput, FetchAttachmentOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            hash: EntryHash,
+        } -> Option<Vec<u8>>;
+        AttachmentLimitsInput, AttachmentLimitsOutput {} -> AttachmentLimits;
+    }
+}
+
+/// DTOs for AnonymousExpressionDao
+pub mod anonymous_expression {
+    use super::*;
+
+    dtos! {
+        CreateAnonymousExpressionInput, CreateAnonymousExpressionOutput {
+            content: String,
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            context: DnaHash,
+        } -> Expression;
+        AnonymousPolicyInput, AnonymousPolicyOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            context: DnaHash,
+        } -> AnonymousPolicy;
+        SetAnonymousPolicyInput, SetAnonymousPolicyOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            context: DnaHash,
+            policy: AnonymousPolicy,
+        } -> ();
+    }
+}
+
+/// DTOs for TaggingDao
+pub mod tagging {
+    use super::*;
+
+    dtos! {
+        TagsForInput, TagsForOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            expression: AnyDhtHash,
+        } -> Vec<Tag>;
+        ExpressionsByTagInput, ExpressionsByTagOutput { tag: Tag, page_size: usize, cursor: Option<String>, ordering: Ordering } -> ExpressionPage;
+        MentionsInInput, MentionsInOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            expression: AnyDhtHash,
+        } -> #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))] Vec<Identity>;
+    }
+}
+
+/// DTOs for AuthoringDao
+pub mod authoring {
+    use super::*;
+
+    dtos! {
+        SaveDraftInput, SaveDraftOutput { content: String } -> #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))] DraftId;
+        UpdateDraftInput, UpdateDraftOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            id: DraftId,
+            content: String,
+        } -> #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))] DraftId;
+        ListDraftsInput, ListDraftsOutput {} -> Vec<Draft>;
+        DeleteDraftInput, DeleteDraftOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            id: DraftId,
+        } -> ();
+        PublishDraftInput, PublishDraftOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            id: DraftId,
+        } -> Expression;
+        ScheduleExpressionInput, ScheduleExpressionOutput { content: String, publish_at: chrono::DateTime<chrono::Utc> } -> #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))] DraftId;
+    }
+}
+
+/// DTOs for SealedExpressionDao
+pub mod sealed_expression {
+    use super::*;
+
+    dtos! {
+        CreateSealedExpressionInput, CreateSealedExpressionOutput {
+            ciphertext: Vec<u8>,
+            reveal_at: chrono::DateTime<chrono::Utc>,
+            #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))]
+            key_escrow: Vec<Identity>,
+        } -> SealedExpression;
+        GetSealedExpressionInput, GetSealedExpressionOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            address: HeaderHash,
+        } -> Option<SealedExpression>;
+    }
+}
+
+/// DTOs for RevealProtocolDao
+pub mod reveal_protocol {
+    use super::*;
+
+    dtos! {
+        RevealInput, RevealOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            sealed: HeaderHash,
+            key: Vec<u8>,
+        } -> Expression;
+        GetRevealedInput, GetRevealedOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            sealed: HeaderHash,
+        } -> Option<Expression>;
+        PendingRevealsInput, PendingRevealsOutput {} -> Vec<SealedExpression>;
+    }
+}
+
+/// DTOs for MirrorableDao
+pub mod mirrorable {
+    use super::*;
+
+    dtos! {
+        SnapshotManifestInput, SnapshotManifestOutput {} -> MirrorDescriptor;
+        FetchSegmentInput, FetchSegmentOutput { id: String } -> Option<MirrorSegment>;
+    }
+}
+
+/// DTOs for ConsentDao
+pub mod consent {
+    use super::*;
+
+    dtos! {
+        GrantBridgeConsentInput, GrantBridgeConsentOutput { bridge_id: String, scopes: Vec<ConsentScope> } -> ConsentRecord;
+        RevokeInput, RevokeOutput { bridge_id: String } -> ();
+        ConsentsInput, ConsentsOutput {} -> Vec<ConsentRecord>;
+        HasConsentInput, HasConsentOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            agent: Identity,
+            bridge_id: String,
+            scope: ConsentScope,
+        } -> bool;
+    }
+}
+
+/// DTOs for EncryptedExpressionDao
+pub mod encrypted_expression {
+    use super::*;
+
+    dtos! {
+        EstablishSessionInput, EstablishSessionOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            with: Identity,
+        } -> EncryptionSession;
+        SendEncryptedInput, SendEncryptedOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            to: Identity,
+            ciphertext_meta: CiphertextMeta,
+        } -> DeliveryStatus;
+        DecryptInput, DecryptOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            expression: AnyDhtHash,
+        } -> String;
+        SupportedSchemesInput, SupportedSchemesOutput {} -> Vec<EncryptionScheme>;
+    }
+}
+
+/// DTOs for SearchDao
+pub mod search {
+    use super::*;
+
+    dtos! {
+        SearchExpressionsInput, SearchExpressionsOutput { query: SearchQuery, page_size: usize, cursor: Option<String>, ordering: Ordering } -> ExpressionPage;
+    }
+}
+
+/// DTOs for CrossPostDao
+pub mod cross_post {
+    use super::*;
+
+    dtos! {
+        ShareIntoInput, ShareIntoOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            context_dna: DnaHash,
+            expression: GlobalEntryRef,
+        } -> GlobalEntryRef;
+        SharedFromInput, SharedFromOutput { expression: GlobalEntryRef } -> Vec<GlobalEntryRef>;
+    }
+}
+
+/// DTOs for ModerationDao
+pub mod moderation {
+    use super::*;
+
+    dtos! {
+        ReportInput, ReportOutput { entry: GlobalEntryRef, reason: ReportReason, details: Option<String> } -> Report;
+        OpenReportsInput, OpenReportsOutput { count: usize, page: usize } -> Vec<Report>;
+        ResolveReportInput, ResolveReportOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            id: HeaderHash,
+            resolution: Resolution,
+        } -> Report;
+        RemoveContentInput, RemoveContentOutput { entry: GlobalEntryRef, reason: ReportReason } -> ();
+    }
+}
+
+/// DTOs for PollsDao
+pub mod polls {
+    use super::*;
+
+    dtos! {
+        CreatePollInput, CreatePollOutput { question: String, options: Vec<String>, multiple_choice: bool, closes_at: Option<chrono::DateTime<chrono::Utc>> } -> Poll;
+        GetPollInput, GetPollOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            poll: HeaderHash,
+        } -> Option<Poll>;
+        VoteInput, VoteOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            poll: HeaderHash,
+            option: usize,
+        } -> ();
+        ResultsInput, ResultsOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            poll: HeaderHash,
+        } -> PollResults;
+    }
+}
+
+/// DTOs for EventsDao
+pub mod events {
+    use super::*;
+
+    dtos! {
+        CreateEventInput, CreateEventOutput { details: EventDetails } -> Event;
+        GetEventInput, GetEventOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            event: HeaderHash,
+        } -> Option<Event>;
+        UpcomingEventsInput, UpcomingEventsOutput { count: usize, page: usize } -> Vec<Event>;
+        RsvpInput, RsvpOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            event: HeaderHash,
+            status: RsvpStatus,
+        } -> ();
+        AttendeesInput, AttendeesOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            event: HeaderHash,
+        } -> Vec<Attendee>;
+    }
+}
+
+/// DTOs for DeviceManagementDao
+pub mod device_management {
+    use super::*;
+
+    dtos! {
+        RegisterDeviceInput, RegisterDeviceOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            device_key: AgentPubKey,
+            name: String,
+        } -> KeyRotationEvent;
+        RevokeDeviceInput, RevokeDeviceOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            device_key: AgentPubKey,
+        } -> KeyRotationEvent;
+        MyDevicesInput, MyDevicesOutput {} -> Vec<Device>;
+        IdentityOfInput, IdentityOfOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            device_key: AgentPubKey,
+        } -> #[cfg_attr(feature = "schema", schemars(with = "Option<crate::schema::HoloHash>"))] Option<Identity>;
+        KeyRotationEventsInput, KeyRotationEventsOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            identity: Identity,
+        } -> Vec<KeyRotationEvent>;
+    }
+}
+
+/// DTOs for CapabilitiesDao
+pub mod capabilities {
+    use super::*;
+
+    dtos! {
+        GrantInput, GrantOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            assignee: Identity,
+            functions: Vec<FnRef>,
+            expiry: Option<chrono::DateTime<chrono::Utc>>,
+        } -> CapabilityGrant;
+        RevokeInput, RevokeOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            grant_id: HeaderHash,
+        } -> ();
+        MyGrantsInput, MyGrantsOutput {} -> Vec<CapabilityGrant>;
+    }
+}
+
+/// DTOs for AttestationsDao
+pub mod attestations {
+    use super::*;
+
+    dtos! {
+        AttestInput, AttestOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            about: Identity,
+            claim: Claim,
+            expiry: Option<chrono::DateTime<chrono::Utc>>,
+        } -> Attestation;
+        AttestationsAboutInput, AttestationsAboutOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            agent: Identity,
+        } -> Vec<Attestation>;
+        RevokeAttestationInput, RevokeAttestationOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            id: HeaderHash,
+        } -> ();
+    }
+}
+
+/// DTOs for NotificationsDao
+pub mod notifications {
+    use super::*;
+
+    dtos! {
+        NotificationsInput, NotificationsOutput { count: usize, page: usize } -> Vec<NotificationItem>;
+        MarkSeenInput, MarkSeenOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))]
+            ids: Vec<HeaderHash>,
+        } -> ();
+        UnseenCountInput, UnseenCountOutput {} -> usize;
+    }
+}
+
+/// DTOs for FeedDao
+pub mod feed {
+    use super::*;
+
+    dtos! {
+        HomeTimelineInput, HomeTimelineOutput { page_size: usize, cursor: Option<String> } -> FeedPage;
+        ContextTimelineInput, ContextTimelineOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            context: DnaHash,
+            page_size: usize,
+            cursor: Option<String>,
+        } -> FeedPage;
+    }
+}
+
+/// DTOs for BookmarksDao
+pub mod bookmarks {
+    use super::*;
+
+    dtos! {
+        BookmarkInput, BookmarkOutput { entry: GlobalEntryRef } -> ();
+        UnbookmarkInput, UnbookmarkOutput { entry: GlobalEntryRef } -> ();
+        MyBookmarksInput, MyBookmarksOutput { count: usize, page: usize } -> Vec<GlobalEntryRef>;
+    }
+}
+
+/// DTOs for ConversationsDao
+pub mod conversations {
+    use super::*;
+
+    dtos! {
+        StartConversationInput, StartConversationOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))]
+            participants: Vec<Identity>,
+        } -> Conversation;
+        SendInConversationInput, SendInConversationOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            conversation: HeaderHash,
+            content: String,
+        } -> ConversationMessage;
+        MyConversationsInput, MyConversationsOutput { count: usize, page: usize } -> Vec<Conversation>;
+        ConversationMessagesInput, ConversationMessagesOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            conversation: HeaderHash,
+            count: usize,
+            page: usize,
+        } -> Vec<ConversationMessage>;
+    }
+}
+
+/// DTOs for RoomsDao
+pub mod rooms {
+    use super::*;
+
+    dtos! {
+        CreateRoomInput, CreateRoomOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            context: DnaHash,
+            name: String,
+        } -> Room;
+        RoomsInput, RoomsOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            context: DnaHash,
+        } -> Vec<Room>;
+        PostMessageInput, PostMessageOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            room: HeaderHash,
+            content: String,
+        } -> RoomMessage;
+        MessagesSinceInput, MessagesSinceOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            room: HeaderHash,
+            timestamp: chrono::DateTime<chrono::Utc>,
+        } -> Vec<RoomMessage>;
+        TypingInput, TypingOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            room: HeaderHash,
+        } -> ();
+    }
+}
+
+/// DTOs for PresenceDao
+pub mod presence {
+    use super::*;
+
+    dtos! {
+        SetStatusInput, SetStatusOutput { status: Status } -> ();
+        StatusOfInput, StatusOfOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            agent: Identity,
+        } -> Option<Status>;
+    }
+}
+
+/// DTOs for ApOutboxDao
+pub mod ap_outbox {
+    use super::*;
+
+    dtos! {
+        PostActivityInput, PostActivityOutput { activity: Activity } -> Activity;
+        UndoInput, UndoOutput { target: UndoTarget } -> Activity;
+        OutboxInput, OutboxOutput { count: usize, page: usize } -> Vec<Activity>;
+    }
+}
+
+/// DTOs for ApInboxDao
+pub mod ap_inbox {
+    use super::*;
+
+    dtos! {
+        DeliverInput, DeliverOutput { activity: Activity } -> InboxResult;
+        InboxInput, InboxOutput { count: usize, page: usize } -> Vec<Activity>;
+        DispatchRulesInput, DispatchRulesOutput {} -> DispatchRules;
+    }
+}
+
+/// DTOs for KeyManagementDao
+pub mod key_management {
+    use super::*;
+
+    dtos! {
+        RotateKeyInput, RotateKeyOutput {} -> PublicKey;
+        CurrentKeyInput, CurrentKeyOutput {} -> PublicKey;
+        SignInput, SignOutput { bytes: Vec<u8> } -> Vec<u8>;
+        VerifyInput, VerifyOutput { actor: String, bytes: Vec<u8>, signature: Vec<u8> } -> bool;
+    }
+}
+
+/// DTOs for WebFingerDao
+pub mod web_finger {
+    use super::*;
+
+    dtos! {
+        ResourceForInput, ResourceForOutput { acct: String } -> Option<WebFingerResource>;
+        ResolveAcctInput, ResolveAcctOutput { acct: String } -> Option<crate::handle::Handle>;
+    }
+}
+
+/// DTOs for ApDeliveryDao
+pub mod ap_delivery {
+    use super::*;
+
+    dtos! {
+        EnqueueInput, EnqueueOutput { activity: Activity, inboxes: Vec<String> } -> Delivery;
+        PendingDeliveriesInput, PendingDeliveriesOutput { count: usize, page: usize } -> Vec<Delivery>;
+        MarkDeliveredInput, MarkDeliveredOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            id: HeaderHash,
+        } -> ();
+        MarkFailedInput, MarkFailedOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            id: HeaderHash,
+            retry_after: chrono::DateTime<chrono::Utc>,
+        } -> Delivery;
+    }
+}
+
+/// DTOs for ActorMigrationDao
+pub mod actor_migration {
+    use super::*;
+
+    dtos! {
+        AnnounceMoveInput, AnnounceMoveOutput { to: String } -> Activity;
+        MovedToInput, MovedToOutput {} -> Option<String>;
+        AlsoKnownAsInput, AlsoKnownAsOutput {} -> Vec<String>;
+        SetAlsoKnownAsInput, SetAlsoKnownAsOutput { aliases: Vec<String> } -> ();
+    }
+}
+
+/// DTOs for DecentralizedIdentityDao
+pub mod decentralized_identity {
+    use super::*;
+
+    dtos! {
+        DidInput, DidOutput {} -> Did;
+        DidDocumentInput, DidDocumentOutput {} -> DidDocument;
+        ResolveInput, ResolveOutput { did: Did } -> Option<DidDocument>;
+        LinkDidInput, LinkDidOutput { external_did: Did, proof: Vec<u8> } -> ();
+    }
+}
+
+/// DTOs for DescribeDao
+pub mod describe {
+    use super::*;
+
+    dtos! {
+        DescribeInput, DescribeOutput {} -> Vec<TraitDescriptor>;
+    }
+}
+
+/// DTOs for TimeIndexDao
+pub mod time_index {
+    use super::*;
+
+    dtos! {
+        ExpressionsBetweenInput, ExpressionsBetweenOutput { start: chrono::DateTime<chrono::Utc>, end: chrono::DateTime<chrono::Utc>, page_size: usize, cursor: Option<String>, ordering: Ordering } -> ExpressionPage;
+    }
+}
+
+/// DTOs for LimitsDao
+pub mod limits {
+    use super::*;
+
+    dtos! {
+        CurrentLimitsInput, CurrentLimitsOutput {} -> DnaLimits;
+    }
+}
+
+/// DTOs for MembraneDao
+pub mod membrane {
+    use super::*;
+
+    dtos! {
+        MembraneRequirementsInput, MembraneRequirementsOutput {} -> MembraneSpec;
+        GenerateProofInput, GenerateProofOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            for_agent: Identity,
+        } -> MembraneProof;
+        VerifyProofInput, VerifyProofOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            agent: Identity,
+            proof: MembraneProof,
+        } -> ValidationResult;
+    }
+}
+
+/// DTOs for InvitationsDao
+pub mod invitations {
+    use super::*;
+
+    dtos! {
+        CreateInviteInput, CreateInviteOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            agent: Identity,
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            dna: DnaHash,
+        } -> Invite;
+        AcceptInviteInput, AcceptInviteOutput { token: String } -> MembraneProof;
+        RevokeInviteInput, RevokeInviteOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            id: HeaderHash,
+        } -> ();
+        PendingInvitesInput, PendingInvitesOutput {} -> Vec<Invite>;
+    }
+}
+
+/// DTOs for CoAuthoringDao
+pub mod co_authoring {
+    use super::*;
+
+    dtos! {
+        ProposeCoauthoredInput, ProposeCoauthoredOutput {
+            content: String,
+            #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))]
+            coauthors: Vec<Identity>,
+        } -> CoAuthorshipProposal;
+        AcceptCoauthorshipInput, AcceptCoauthorshipOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            proposal: HeaderHash,
+        } -> CoAuthorshipProposal;
+        FinalizeInput, FinalizeOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            proposal: HeaderHash,
+        } -> Expression;
+    }
+}
+
+/// DTOs for OrgProfileDao
+pub mod org_profile {
+    use super::*;
+
+    dtos! {
+        AddAdminInput, AddAdminOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            agent: Identity,
+        } -> ();
+        RemoveAdminInput, RemoveAdminOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            agent: Identity,
+        } -> ();
+        AdminsInput, AdminsOutput {} -> #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))] Vec<Identity>;
+        PostAsOrgInput, PostAsOrgOutput { content: String } -> Expression;
+    }
+}
+
+/// DTOs for DedupDao
+pub mod dedup {
+    use super::*;
+
+    dtos! {
+        FindByContentHashInput, FindByContentHashOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            hash: EntryHash,
+        } -> Vec<GlobalEntryRef>;
+        CanonicalForInput, CanonicalForOutput { url: String } -> Option<GlobalEntryRef>;
+    }
+}
+
+/// DTOs for PreviewsDao
+pub mod previews {
+    use super::*;
+
+    dtos! {
+        StorePreviewInput, StorePreviewOutput { preview: UnfurlMetadata } -> #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))] EntryHash;
+        GetPreviewInput, GetPreviewOutput { url: String } -> Option<UnfurlMetadata>;
+    }
+}
+
+/// DTOs for SubscriptionsDao
+pub mod subscriptions {
+    use super::*;
+
+    dtos! {
+        SubscribeTagInput, SubscribeTagOutput { tag: Tag } -> Subscription;
+        SubscribeContextInput, SubscribeContextOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            dna: DnaHash,
+        } -> Subscription;
+        MySubscriptionsInput, MySubscriptionsOutput {} -> Vec<Subscription>;
+        UnsubscribeInput, UnsubscribeOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            subscription_address: HeaderHash,
+        } -> ();
+    }
+}
+
+/// DTOs for FeedFiltersDao
+pub mod feed_filters {
+    use super::*;
+
+    dtos! {
+        AddFilterInput, AddFilterOutput { rule: FilterRule } -> FeedFilter;
+        MyFiltersInput, MyFiltersOutput {} -> Vec<FeedFilter>;
+        RemoveFilterInput, RemoveFilterOutput {
+            #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+            filter_address: HeaderHash,
+        } -> ();
+    }
+}
+
+#[cfg(feature = "schema")]
+pub(crate) fn add_schemas(
+    schemas: &mut std::collections::HashMap<String, schemars::schema::RootSchema>,
+) {
+    social_graph::add_schemas(schemas);
+    social_context::add_schemas(schemas);
+    social_context_permissions::add_schemas(schemas);
+    expression::add_schemas(schemas);
+    inter_dna::add_schemas(schemas);
+    link_policy::add_schemas(schemas);
+    read_token::add_schemas(schemas);
+    attachments::add_schemas(schemas);
+    anonymous_expression::add_schemas(schemas);
+    tagging::add_schemas(schemas);
+    authoring::add_schemas(schemas);
+    sealed_expression::add_schemas(schemas);
+    reveal_protocol::add_schemas(schemas);
+    mirrorable::add_schemas(schemas);
+    consent::add_schemas(schemas);
+    encrypted_expression::add_schemas(schemas);
+    search::add_schemas(schemas);
+    cross_post::add_schemas(schemas);
+    moderation::add_schemas(schemas);
+    polls::add_schemas(schemas);
+    events::add_schemas(schemas);
+    device_management::add_schemas(schemas);
+    capabilities::add_schemas(schemas);
+    attestations::add_schemas(schemas);
+    notifications::add_schemas(schemas);
+    feed::add_schemas(schemas);
+    bookmarks::add_schemas(schemas);
+    conversations::add_schemas(schemas);
+    rooms::add_schemas(schemas);
+    presence::add_schemas(schemas);
+    ap_outbox::add_schemas(schemas);
+    ap_inbox::add_schemas(schemas);
+    key_management::add_schemas(schemas);
+    web_finger::add_schemas(schemas);
+    ap_delivery::add_schemas(schemas);
+    actor_migration::add_schemas(schemas);
+    decentralized_identity::add_schemas(schemas);
+    describe::add_schemas(schemas);
+    time_index::add_schemas(schemas);
+    limits::add_schemas(schemas);
+    membrane::add_schemas(schemas);
+    invitations::add_schemas(schemas);
+    co_authoring::add_schemas(schemas);
+    org_profile::add_schemas(schemas);
+    dedup::add_schemas(schemas);
+    previews::add_schemas(schemas);
+    subscriptions::add_schemas(schemas);
+    feed_filters::add_schemas(schemas);
+}
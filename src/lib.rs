@@ -1,28 +1,441 @@
 use hdk3::prelude::*;
 use holo_hash::DnaHash;
+pub use limits::RateLimit;
+pub use meta_traits_macros::expose_zome;
+
+pub mod activitypub;
+pub mod canonical;
+#[cfg(feature = "client")]
+pub mod client;
+pub mod conformance;
+pub mod dedup;
+pub mod did;
+pub mod errors;
+pub mod handle;
+pub mod indexing;
+pub mod install;
+pub mod io;
+pub mod limits;
+pub mod previews;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod reference;
+pub mod registry;
+#[cfg(feature = "schema")]
+pub mod schema;
+pub mod signals;
+pub mod validation;
+pub mod verification;
 
 pub type Identity = AgentPubKey;
 
-#[derive(Serialize, Deserialize, Clone, SerializedBytes)]
+/// Reference to an entry in some DNA. Canonical string form is `hc://<dna>/<entry_address>`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GlobalEntryRef {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
     pub dna: DnaHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
     pub entry_address: HeaderHash,
 }
 
+impl activitypub::ToActivityStreams for GlobalEntryRef {
+    /// AS2 id of the referenced entry
+    fn to_activity_streams(&self) -> serde_json::Value {
+        serde_json::Value::String(self.to_string())
+    }
+}
+
+impl activitypub::FromActivityStreams for GlobalEntryRef {
+    /// Parse from an AS2 id or an object with an hc:// id
+    fn from_activity_streams(value: &serde_json::Value) -> Result<Self, errors::TraitError> {
+        let id = match value {
+            serde_json::Value::Object(object) => object.get("id").and_then(|id| id.as_str()),
+            other => other.as_str(),
+        };
+        id.ok_or_else(|| errors::TraitError::InvalidInput(String::from("missing id")))?
+            .parse()
+    }
+}
+
+pub const GLOBAL_ENTRY_REF_SCHEME: &str = "hc://";
+
+impl GlobalEntryRef {
+    pub fn new(dna: DnaHash, entry_address: HeaderHash) -> Self {
+        GlobalEntryRef { dna, entry_address }
+    }
+}
+
+impl std::fmt::Display for GlobalEntryRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}/{}",
+            GLOBAL_ENTRY_REF_SCHEME, self.dna, self.entry_address
+        )
+    }
+}
+
+impl std::str::FromStr for GlobalEntryRef {
+    type Err = errors::TraitError;
+
+    fn from_str(uri: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            || errors::TraitError::InvalidInput(format!("{} is not a valid hc:// uri", uri));
+        let mut parts = uri
+            .strip_prefix(GLOBAL_ENTRY_REF_SCHEME)
+            .ok_or_else(invalid)?
+            .split('/');
+        let (dna, entry_address) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(dna), Some(entry_address), None) => (dna, entry_address),
+            _ => return Err(invalid()),
+        };
+        use std::convert::TryFrom;
+        Ok(GlobalEntryRef {
+            dna: DnaHash::try_from(dna).map_err(|_| invalid())?,
+            entry_address: HeaderHash::try_from(entry_address).map_err(|_| invalid())?,
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GlobalEntryRefChunked {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
     pub dna: DnaHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
     pub entry_address: HeaderHash,
     pub chunk: u32
 }
 
 /// A holochain expression
 #[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Expression {
+    #[cfg_attr(feature = "schema", schemars(with = "serde_json::Value"))]
     pub expression: Element,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
     pub expression_dna: DnaHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
     pub creator: AgentPubKey,
-    pub created_at: chrono::DateTime<chrono::Utc>
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Creator's signature over verification::ExpressionSignable; allows provenance to be checked once forwarded out of expression_dna
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub signature: Option<Signature>,
+    /// Set when clients should hide content behind a click-through warning
+    pub sensitivity: Option<ContentWarning>,
+    pub language: Option<LanguageTag>,
+    /// Public for expressions created before audiences existed
+    #[serde(default)]
+    pub audience: Audience,
+    /// Every agent who signed a co-authored expression, including creator; empty for single author expressions
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))]
+    pub authors: Vec<Identity>,
+    /// Set when creator posted as an admin of an organization rather than as themselves
+    #[serde(default)]
+    pub on_behalf_of: Option<OnBehalfOf>,
+}
+
+/// Attribution of an expression to the organization it was posted on behalf of
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct OnBehalfOf {
+    /// Social context DNA of the organization
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub org: DnaHash,
+    pub org_name: String,
+}
+
+/// Identifier of an agent defined list of other agents
+pub type ListId = String;
+
+pub const AS_PUBLIC: &str = "https://www.w3.org/ns/activitystreams#Public";
+
+/// Who an expression is intended for. Membraned DNAs and bridges should only show/deliver expressions to this audience
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Audience {
+    Public,
+    Followers,
+    List(ListId),
+    Context(#[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))] DnaHash),
+    Agents(
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))]
+        Vec<Identity>,
+    ),
+}
+
+impl Default for Audience {
+    fn default() -> Self {
+        Audience::Public
+    }
+}
+
+impl Audience {
+    /// AS2 addressing properties (to/cc/audience) for this audience where actor_id is the id of the creator's actor
+    pub fn to_activity_streams(&self, actor_id: &str) -> serde_json::Value {
+        let followers = format!("{}/followers", actor_id);
+        match self {
+            Audience::Public => serde_json::json!({ "to": [AS_PUBLIC], "cc": [followers] }),
+            Audience::Followers => serde_json::json!({ "to": [followers] }),
+            Audience::List(list) => {
+                serde_json::json!({ "to": [format!("{}/lists/{}", actor_id, list)] })
+            }
+            Audience::Context(dna) => serde_json::json!({ "audience": format!("{}", dna) }),
+            Audience::Agents(agents) => serde_json::json!({
+                "to": agents.iter().map(|agent| format!("{}", agent)).collect::<Vec<String>>()
+            }),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SensitivityCategory {
+    Nudity,
+    Violence,
+    Spoilers,
+    Politics,
+    Health,
+    Other(String),
+}
+
+/// Warning shown in place of an expression's content until the viewer chooses to reveal it
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ContentWarning {
+    pub summary: String,
+    pub categories: Vec<SensitivityCategory>,
+}
+
+impl ContentWarning {
+    /// AS2 properties for this warning; merged into the object an expression is serialized to
+    pub fn to_activity_streams(&self) -> serde_json::Value {
+        serde_json::json!({
+            "summary": self.summary,
+            "sensitive": true,
+        })
+    }
+}
+
+/// BCP-47 language tag, i.e "en", "pt-BR" or "zh-Hant-TW". Subtags are normalized to their conventional case
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(try_from = "String", into = "String")]
+pub struct LanguageTag(String);
+
+impl LanguageTag {
+    pub fn new(raw: &str) -> Result<LanguageTag, errors::TraitError> {
+        let invalid = || {
+            errors::TraitError::InvalidInput(format!("{} is not a valid BCP-47 language tag", raw))
+        };
+        let mut subtags = raw.trim().split('-');
+        let primary = subtags.next().unwrap_or("");
+        if !(2..=8).contains(&primary.len())
+            || primary.len() == 4
+            || !primary.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            return Err(invalid());
+        }
+        let mut normalized = vec![primary.to_ascii_lowercase()];
+        for subtag in subtags {
+            if subtag.is_empty()
+                || subtag.len() > 8
+                || !subtag.chars().all(|c| c.is_ascii_alphanumeric())
+            {
+                return Err(invalid());
+            }
+            let subtag = match subtag.len() {
+                // Region
+                2 if subtag.chars().all(|c| c.is_ascii_alphabetic()) => subtag.to_ascii_uppercase(),
+                // Script
+                4 if subtag.chars().all(|c| c.is_ascii_alphabetic()) => {
+                    let lower = subtag.to_ascii_lowercase();
+                    lower[..1].to_ascii_uppercase() + &lower[1..]
+                }
+                _ => subtag.to_ascii_lowercase(),
+            };
+            normalized.push(subtag);
+        }
+        Ok(LanguageTag(normalized.join("-")))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Primary language subtag; i.e "pt" for "pt-BR"
+    pub fn primary(&self) -> &str {
+        self.0.split('-').next().unwrap_or(&self.0)
+    }
+
+    /// True if this tag is equal to or more specific than range; "en-GB" matches "en"
+    pub fn matches(&self, range: &LanguageTag) -> bool {
+        self.0 == range.0 || self.0.starts_with(&format!("{}-", range.0))
+    }
+
+    /// AS2 JSON-LD context entry declaring this as the default language of an object
+    pub fn to_activity_streams_context(&self) -> serde_json::Value {
+        serde_json::json!({ "@language": self.0 })
+    }
+}
+
+impl std::convert::TryFrom<String> for LanguageTag {
+    type Error = errors::TraitError;
+
+    fn try_from(raw: String) -> Result<Self, Self::Error> {
+        LanguageTag::new(&raw)
+    }
+}
+
+impl From<LanguageTag> for String {
+    fn from(tag: LanguageTag) -> String {
+        tag.0
+    }
+}
+
+/// Filter on the language of expressions. Expressions without a language only pass Any and Exclude
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum LanguageFilter {
+    Any,
+    Only(Vec<LanguageTag>),
+    Exclude(Vec<LanguageTag>),
+}
+
+impl LanguageFilter {
+    pub fn allows(&self, language: Option<&LanguageTag>) -> bool {
+        match (self, language) {
+            (LanguageFilter::Any, _) => true,
+            (LanguageFilter::Only(ranges), Some(language)) => {
+                ranges.iter().any(|range| language.matches(range))
+            }
+            (LanguageFilter::Only(_), None) => false,
+            (LanguageFilter::Exclude(ranges), Some(language)) => {
+                !ranges.iter().any(|range| language.matches(range))
+            }
+            (LanguageFilter::Exclude(_), None) => true,
+        }
+    }
+}
+
+impl activitypub::ToActivityStreams for Expression {
+    /// AS2 Note for this expression. Content of the expression's entry is DNA specific so is left to the
+    /// implementation to add
+    fn to_activity_streams(&self) -> serde_json::Value {
+        let id = GlobalEntryRef::new(
+            self.expression_dna.clone(),
+            self.expression.header_address().clone(),
+        );
+        let creator = format!("{}", self.creator);
+        let mut attributed_to: Vec<String> = if self.authors.is_empty() {
+            vec![creator.clone()]
+        } else {
+            self.authors
+                .iter()
+                .map(|author| author.to_string())
+                .collect()
+        };
+        if let Some(on_behalf_of) = &self.on_behalf_of {
+            attributed_to.insert(0, on_behalf_of.org.to_string());
+        }
+        let attributed_to = match attributed_to.len() {
+            1 => serde_json::Value::from(attributed_to.remove(0)),
+            _ => serde_json::Value::from(attributed_to),
+        };
+        let mut note = serde_json::json!({
+            "type": "Note",
+            "id": id.to_string(),
+            "attributedTo": attributed_to,
+            "published": self.created_at.to_rfc3339(),
+            "expressionDna": format!("{}", self.expression_dna),
+        });
+        let mut properties = vec![self.audience.to_activity_streams(&creator)];
+        if let Some(sensitivity) = &self.sensitivity {
+            properties.push(sensitivity.to_activity_streams());
+        }
+        for property in properties {
+            if let (Some(note), serde_json::Value::Object(property)) =
+                (note.as_object_mut(), property)
+            {
+                note.extend(property);
+            }
+        }
+        let mut context = vec![serde_json::Value::from(activitypub::holochain_ap_context())];
+        if let Some(language) = &self.language {
+            context.push(language.to_activity_streams_context());
+        }
+        note["@context"] = serde_json::Value::Array(context);
+        note
+    }
+}
+
+/// Order in which paged expressions are returned
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Ordering {
+    NewestFirst,
+    OldestFirst,
+}
+
+/// Whether expressions which are replies to other expressions are included
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ReplyFilter {
+    Any,
+    OnlyReplies,
+    NoReplies,
+}
+
+/// Criteria for querying expressions. New criteria are added as fields defaulting to no filtering, so
+/// callers constructing a filter with `..Default::default()` are unaffected
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExpressionFilter {
+    /// MIME types of content to include; i.e "text/plain" or "image/png". Empty includes every type
+    pub content_types: Vec<String>,
+    /// Only include expressions tagged with any of tags. Empty includes untagged expressions
+    pub tags: Vec<Tag>,
+    pub replies: ReplyFilter,
+    pub language: LanguageFilter,
+    /// Only include expressions created at or after since
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only include expressions created before until
+    pub until: Option<chrono::DateTime<chrono::Utc>>,
+    pub ordering: Ordering,
+}
+
+impl Default for ExpressionFilter {
+    fn default() -> Self {
+        ExpressionFilter {
+            content_types: Vec::new(),
+            tags: Vec::new(),
+            replies: ReplyFilter::Any,
+            language: LanguageFilter::Any,
+            since: None,
+            until: None,
+            ordering: Ordering::NewestFirst,
+        }
+    }
+}
+
+impl ExpressionFilter {
+    /// Whether created_at falls within since and until
+    pub fn in_time_range(&self, created_at: chrono::DateTime<chrono::Utc>) -> bool {
+        self.since.map(|since| created_at >= since).unwrap_or(true)
+            && self.until.map(|until| created_at < until).unwrap_or(true)
+    }
+}
+
+/// A page of expressions. next_cursor should be passed back to fetch the following page
+/// and is None once the end of the collection has been reached
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExpressionPage {
+    pub items: Vec<Expression>,
+    pub next_cursor: Option<String>,
+    /// Best effort estimate of the total number of items; may be None if the DNA cannot cheaply count
+    pub total_hint: Option<usize>,
 }
 
 /// Trait that provides an interface for creating and maintaining a social graph
@@ -56,6 +469,7 @@ pub trait SocialGraphDao {
     ) -> ExternResult<Vec<Identity>>;
 
     fn follow(target_agent: Identity, by: Option<String>) -> ExternResult<()>;
+    /// Stop following target_agent; a by of None removes follows made with any by
     fn unfollow(target_agent: Identity, by: Option<String>) -> ExternResult<()>;
 
     // Connection Related Operations (i.e. bidirectional friendship)
@@ -109,6 +523,144 @@ pub trait SocialContextDao {
     /// Get agents who are a part of this social context
     /// optional to not force every implementation to create a global list of members - might be ok for small DHTs
     fn members(count: usize, page: usize) -> ExternResult<Option<Vec<Identity>>>;
+
+    // Membership Related Operations; membrane of the host DNA may still enforce who can join
+    fn join() -> ExternResult<()>;
+    fn leave() -> ExternResult<()>;
+    fn is_member(agent: Identity) -> ExternResult<bool>;
+    /// Invite an agent to join this social context
+    fn invite(agent: Identity) -> ExternResult<ContextInvite>;
+    fn accept_invite(invite: HeaderHash) -> ExternResult<()>;
+    fn decline_invite(invite: HeaderHash) -> ExternResult<()>;
+    /// Get invites to this social context sent to current agent which are yet to be accepted or declined
+    fn pending_invites() -> ExternResult<Vec<ContextInvite>>;
+
+    fn get_context_info() -> ExternResult<SocialContextProfile>;
+    /// Update name, description, image, visibility and posting_policy; created_by cannot be changed
+    fn update_context_info(profile: SocialContextProfile) -> ExternResult<SocialContextProfile>;
+
+    /// Pin an expression to the top of this social context (AS2 featured collection); moderators only
+    fn pin_expression(entry: GlobalEntryRef) -> ExternResult<()>;
+    fn unpin_expression(entry: GlobalEntryRef) -> ExternResult<()>;
+    /// Get pinned expressions, most recently pinned first
+    fn pinned() -> ExternResult<Vec<GlobalEntryRef>>;
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ContextVisibility {
+    /// Listed and readable by anyone
+    Public,
+    /// Readable by anyone who knows the DNA but not listed
+    Unlisted,
+    /// Readable by members only
+    Private,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum PostingPolicy {
+    Anyone,
+    MembersOnly,
+    /// Only agents with a role having the Post capability may post
+    ByRole,
+    /// Posts are held until approved by a moderator
+    Moderated,
+}
+
+/// Information needed to render a header for a social context
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SocialContextProfile {
+    pub name: String,
+    pub description: Option<String>,
+    /// Hash of an Attachment stored via AttachmentsDao
+    #[cfg_attr(feature = "schema", schemars(with = "Option<crate::schema::HoloHash>"))]
+    pub image: Option<EntryHash>,
+    pub visibility: ContextVisibility,
+    pub posting_policy: PostingPolicy,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub created_by: Identity,
+}
+
+impl activitypub::ToActivityStreams for SocialContextProfile {
+    /// AS2 Group actor for the social context
+    fn to_activity_streams(&self) -> serde_json::Value {
+        let mut group = serde_json::json!({
+            "type": "Group",
+            "name": self.name,
+            "attributedTo": format!("{}", self.created_by),
+            "discoverable": self.visibility == ContextVisibility::Public,
+        });
+        if let Some(description) = &self.description {
+            group["summary"] = serde_json::Value::String(description.clone());
+        }
+        if let Some(image) = &self.image {
+            group["icon"] = serde_json::json!({ "type": "Image", "url": format!("{}", image) });
+        }
+        group
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Role {
+    Admin,
+    Moderator,
+    Member,
+    Custom(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Capability {
+    Post,
+    DeleteOthers,
+    Invite,
+    ChangeSettings,
+}
+
+impl Role {
+    /// Capabilities a role has unless the social context configures otherwise. Custom roles have none by default
+    pub fn default_capabilities(&self) -> Vec<Capability> {
+        match self {
+            Role::Admin => vec![
+                Capability::Post,
+                Capability::DeleteOthers,
+                Capability::Invite,
+                Capability::ChangeSettings,
+            ],
+            Role::Moderator => vec![
+                Capability::Post,
+                Capability::DeleteOthers,
+                Capability::Invite,
+            ],
+            Role::Member => vec![Capability::Post],
+            Role::Custom(_) => vec![],
+        }
+    }
+}
+
+/// Roles and permissions for members of a social context
+pub trait SocialContextPermissionsDao: SocialContextDao {
+    /// Assign role to a member; requires current agent to have ChangeSettings capability
+    fn assign_role(agent: Identity, role: Role) -> ExternResult<()>;
+    /// Get role of agent; None if agent is not a member
+    fn role_of(agent: Identity) -> ExternResult<Option<Role>>;
+    fn can(agent: Identity, capability: Capability) -> ExternResult<bool>;
+}
+
+/// Entry inviting an agent to join a social context
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ContextInvite {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub address: HeaderHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub inviter: Identity,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub invitee: Identity,
+    pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
 /// An interface into a DNA which contains Expression information. Expected to be interacted with using expression Addresses
@@ -120,31 +672,1603 @@ pub trait SocialContextDao {
 pub trait ExpressionDao {
     /// Create an expression and link it to yourself publicly with optional dna_address pointing to
     /// dna that should ideally be used for linking any comments to this expression
-    fn create_public_expression(content: String) -> ExternResult<Expression>;
-    /// Get expressions authored by a given Agent/Identity
+    fn create_public_expression(content: String, audience: Audience) -> ExternResult<Expression>;
+    /// Get expressions authored by a given Agent/Identity which pass filter. Cursor should be None for the first page
+    /// and then the next_cursor of the previously returned page, so that new expressions do not shift pages
     fn get_by_author(
         author: Identity,
+        filter: ExpressionFilter,
         page_size: usize,
-        page_number: usize,
-    ) -> ExternResult<Vec<Expression>>;
+        cursor: Option<String>,
+    ) -> ExternResult<ExpressionPage>;
     fn get_expression_by_address(address: AnyDhtHash) -> ExternResult<Option<Expression>>;
+    /// Get many expressions in one call; result is in the same order as addresses with None for any not found
+    fn get_many_by_address(addresses: Vec<AnyDhtHash>) -> ExternResult<Vec<Option<Expression>>>;
+
+    /// Delete an expression authored by current agent, leaving a Tombstone in its place
+    fn delete_expression(address: AnyDhtHash) -> ExternResult<Tombstone>;
+    fn is_deleted(address: AnyDhtHash) -> ExternResult<bool>;
+    /// Get tombstones of expressions deleted since timestamp; used by caches, mirrors and bridges to propagate deletions
+    fn tombstones_since(timestamp: chrono::DateTime<chrono::Utc>) -> ExternResult<Vec<Tombstone>>;
+
+    /// Share an expression, possibly from another DNA, with your followers (AS2 Announce)
+    fn boost(expression: GlobalEntryRef) -> ExternResult<Expression>;
+    /// Share an expression along with your own commentary on it
+    fn quote(expression: GlobalEntryRef, commentary: String) -> ExternResult<Expression>;
+    /// Get boosts of an expression made in this DNA
+    fn boosts_of(expression: GlobalEntryRef, count: usize, page: usize) -> ExternResult<Vec<Expression>>;
+    /// Get quotes of an expression made in this DNA
+    fn quotes_of(expression: GlobalEntryRef, count: usize, page: usize) -> ExternResult<Vec<Expression>>;
 
     /// Send an expression to someone privately p2p
-    fn send_private(to: Identity, content: String) -> ExternResult<String>;
+    fn send_private(to: Identity, content: String) -> ExternResult<DeliveryStatus>;
+    /// Get private expressions sent by current agent which have not yet been delivered
+    fn pending_outbox() -> ExternResult<Vec<PendingMessage>>;
     /// Get private expressions sent to you optionally filtered by sender address
     fn inbox(
         from: Option<Identity>,
         page_size: usize,
         page_number: usize,
     ) -> ExternResult<Vec<Expression>>;
+    /// Get private expressions sent to you, newest first, optionally filtered by sender address
+    fn inbox_paged(
+        from: Option<Identity>,
+        page_size: usize,
+        cursor: Option<String>,
+    ) -> ExternResult<ExpressionPage>;
+    /// Mark a private expression as read and send a ReadReceipt signal to its sender
+    fn mark_read(expression: AnyDhtHash) -> ExternResult<()>;
+    fn unread_count() -> ExternResult<usize>;
+    fn delete_from_inbox(expression: AnyDhtHash) -> ExternResult<()>;
+}
+
+/// Outcome of sending a private expression
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum DeliveryStatus {
+    /// Recipient received the expression directly
+    Delivered,
+    /// Recipient could not be reached; expression is held in pending_outbox/DHT queue for later delivery
+    Queued {
+        retry_hint: Option<chrono::DateTime<chrono::Utc>>,
+    },
+    /// Recipient refused the expression
+    Rejected { reason: String },
+}
+
+/// Private expression which has not yet been delivered to its recipient
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PendingMessage {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub to: Identity,
+    pub content: String,
+    pub queued_at: chrono::DateTime<chrono::Utc>,
+    pub attempts: u32,
+}
+
+/// Entry left in place of a deleted expression
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Tombstone {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub expression: AnyDhtHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub expression_dna: DnaHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub deleted_by: Identity,
+    pub deleted_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl activitypub::ToActivityStreams for Tombstone {
+    /// AS2 Tombstone object for the deleted expression; wrapped in a Delete activity when federated
+    fn to_activity_streams(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "Tombstone",
+            "id": format!("{}", self.expression),
+            "formerType": "Note",
+            "deleted": self.deleted_at.to_rfc3339(),
+        })
+    }
+}
+
+/// Signal sent to the sender of a private expression when the recipient marks it as read
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ReadReceipt {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub expression: AnyDhtHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub reader: Identity,
+    pub read_at: chrono::DateTime<chrono::Utc>,
 }
 
 /// Interface for cross DNA links. Allows for the discovery of new DNA's/entries from a known source DNA/entry.
 /// Host DNA should most likely implement strong anti spam logic if this is to be a public - unmembraned DNA.
+///
+/// Links are typed so that cross DNA graphs can be queried by the meaning of an edge; i.e only the comments on an expression.
 pub trait InterDNADao {
-    fn create_link(source: GlobalEntryRef, target: GlobalEntryRef) -> ExternResult<()>;
-    fn remove_link(source: GlobalEntryRef, target: GlobalEntryRef) -> ExternResult<()>;
+    /// Create link with optional JSON metadata describing the link further.
+    /// proof_of_work is required if the host DNA's LinkPolicyDao sets a required_work
+    fn create_link(
+        source: GlobalEntryRef,
+        target: GlobalEntryRef,
+        link_type: LinkType,
+        metadata: Option<String>,
+        proof_of_work: Option<Nonce>,
+    ) -> ExternResult<InterDnaLink>;
+    /// Create many links in a single zome call. Writes in a zome call are committed atomically, so either every
+    /// link is created or none are. Each link is (source, target, link_type, proof_of_work); a nonce only satisfies
+    /// LinkPolicyDao::validate_link for its own source and target, so each link carries its own
+    fn create_links(
+        links: Vec<(GlobalEntryRef, GlobalEntryRef, LinkType, Option<Nonce>)>,
+    ) -> ExternResult<BatchResult>;
+    /// Remove link between source and target; returns the removed link
+    fn remove_link(source: GlobalEntryRef, target: GlobalEntryRef) -> ExternResult<InterDnaLink>;
+
+    /// Get targets linked from source, optionally only those with link_type
+    fn get_outgoing(
+        source: GlobalEntryRef,
+        link_type: Option<LinkType>,
+        count: usize,
+        page: usize,
+    ) -> ExternResult<Vec<GlobalEntryRef>>;
+    /// Get sources linking to target, optionally only those with link_type
+    fn get_incoming(
+        target: GlobalEntryRef,
+        link_type: Option<LinkType>,
+        count: usize,
+        page: usize,
+    ) -> ExternResult<Vec<GlobalEntryRef>>;
+
+    /// Get targets linked from source which live in any of dnas, grouped by DNA
+    fn get_outgoing_multi(
+        source: GlobalEntryRef,
+        dnas: Vec<DnaHash>,
+        link_type: Option<LinkType>,
+        count: usize,
+        page: usize,
+    ) -> ExternResult<Vec<LinksByDna>>;
+    /// Get every target linked from source regardless of link type, grouped by DNA; i.e to show
+    /// every community an expression is discussed in
+    fn get_all_outgoing(
+        source: GlobalEntryRef,
+        count: usize,
+        page: usize,
+    ) -> ExternResult<Vec<LinksByDna>>;
+
+    /// Count targets linked from source without fetching them; i.e to show number of comments
+    fn count_outgoing(source: GlobalEntryRef, link_type: Option<LinkType>) -> ExternResult<usize>;
+    fn count_incoming(target: GlobalEntryRef, link_type: Option<LinkType>) -> ExternResult<usize>;
+
+    /// Get translations of expression along with their language; i.e targets of its LinkType::Translation links
+    fn translations_of(
+        expression: GlobalEntryRef,
+    ) -> ExternResult<Vec<(LanguageTag, GlobalEntryRef)>>;
+}
+
+/// Entry references which all live in the same DNA
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LinksByDna {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub dna: DnaHash,
+    pub entries: Vec<GlobalEntryRef>,
+}
+
+/// Group refs by their DNA, keeping DNAs and entries in the order they first appear
+pub fn group_by_dna(refs: Vec<GlobalEntryRef>) -> Vec<LinksByDna> {
+    let mut groups: Vec<LinksByDna> = Vec::new();
+    for entry in refs {
+        match groups.iter_mut().find(|group| group.dna == entry.dna) {
+            Some(group) => group.entries.push(entry),
+            None => groups.push(LinksByDna {
+                dna: entry.dna.clone(),
+                entries: vec![entry],
+            }),
+        }
+    }
+    groups
+}
+
+/// Link between entries in two DNAs as stored by an InterDNADao DNA
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct InterDnaLink {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub address: HeaderHash,
+    pub source: GlobalEntryRef,
+    pub target: GlobalEntryRef,
+    pub link_type: LinkType,
+    pub metadata: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub author: Identity,
+}
+
+impl activitypub::ToActivityStreams for InterDnaLink {
+    /// AS2 Relationship object describing this link
+    fn to_activity_streams(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "Relationship",
+            "id": format!("{}", self.address),
+            "subject": self.source.to_string(),
+            "object": self.target.to_string(),
+            "relationship": serde_json::to_value(&self.link_type).unwrap_or(serde_json::Value::Null),
+            "attributedTo": format!("{}", self.author),
+            "published": self.created_at.to_rfc3339(),
+        })
+    }
+}
+
+/// Nonce found by the link author such that the hash of (author, source, target, nonce) has the
+/// number of leading zero bits required by the host DNA's LinkPolicyDao
+pub type Nonce = u64;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ValidationResult {
+    Valid,
+    Invalid(String),
+    /// Author has exceeded the rate limit; retry after the given time
+    RateLimited {
+        retry_after: chrono::DateTime<chrono::Utc>,
+    },
+    /// proof_of_work was missing or does not meet required_work
+    InsufficientWork,
+}
+
+/// Anti spam policy of a public InterDNA DNA. Should be called from the DNA's link validation callbacks
+/// and can be called by clients to pre-check a link before creating it.
+pub trait LinkPolicyDao {
+    fn validate_link(
+        author: Identity,
+        source: GlobalEntryRef,
+        target: GlobalEntryRef,
+        proof_of_work: Option<Nonce>,
+    ) -> ExternResult<ValidationResult>;
+    fn rate_limit() -> ExternResult<Option<RateLimit>>;
+    /// Number of leading zero bits required of link proof of work; None if no proof of work is required
+    fn required_work() -> ExternResult<Option<u32>>;
+}
+
+/// Result of an atomic batch write
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum BatchResult {
+    AllCreated {
+        count: usize,
+    },
+    /// Nothing was written; index is the position in the batch of the first item which failed
+    Failed {
+        index: usize,
+        error: errors::TraitError,
+    },
+}
+
+/// Meaning of a link between two entries
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum LinkType {
+    /// Target is a comment on source
+    Comment,
+    /// Source references target
+    Reference,
+    /// Target is a translation of source into language
+    Translation {
+        language: LanguageTag,
+    },
+    /// Source is a reply to target
+    ReplyTo,
+    Custom(String),
+}
+
+/// Token granting a single agent read access to a private collection without them being a member of its membrane
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ReadToken {
+    /// Address of the token entry; used for revocation
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub address: HeaderHash,
+    pub collection: String,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub audience: Identity,
+    pub secret: String,
+    pub expiry: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Interface for sharing private collections (private streams, inboxes etc) of a membraned DNA with specific agents.
+/// Tokens are bound to the audience agent; host DNA should reject reads from any other agent or after expiry/revocation.
+pub trait ReadTokenDao {
+    fn issue_read_token(
+        collection: String,
+        audience: Identity,
+        expiry: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ExternResult<ReadToken>;
+    fn read_with_token(
+        collection: String,
+        token: ReadToken,
+        cursor: Option<String>,
+    ) -> ExternResult<ExpressionPage>;
+    fn revoke_read_token(token_address: HeaderHash) -> ExternResult<()>;
+    /// Get tokens issued by current agent for a collection which have not been revoked
+    fn issued_read_tokens(collection: String) -> ExternResult<Vec<ReadToken>>;
+}
+
+/// Binary media stored as a series of chunk entries and referenced from expressions
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Attachment {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub hash: EntryHash,
+    pub mime_type: String,
+    /// Total size in bytes of the attachment once chunks are joined
+    pub size: usize,
+    /// Chunk entries in the order they should be joined
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))]
+    pub chunk_addresses: Vec<EntryHash>,
+}
+
+/// Limits a DNA places on uploaded attachments
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AttachmentLimits {
+    pub max_size: usize,
+    pub max_chunk_size: usize,
+    /// Allowed mime types; empty means any mime type is accepted
+    pub allowed_mime_types: Vec<String>,
+}
+
+impl AttachmentLimits {
+    /// Check an upload against these limits before any chunks are written
+    pub fn validate(&self, mime_type: &str, size: usize) -> Result<(), errors::TraitError> {
+        if self.max_chunk_size == 0 {
+            return Err(errors::TraitError::InvalidInput(String::from(
+                "max_chunk_size of attachment limits must be greater than 0",
+            )));
+        }
+        if size > self.max_size {
+            return Err(errors::TraitError::InvalidInput(format!(
+                "attachment of {} bytes exceeds limit of {} bytes",
+                size, self.max_size
+            )));
+        }
+        if !self.allowed_mime_types.is_empty()
+            && !self.allowed_mime_types.iter().any(|allowed| allowed == mime_type)
+        {
+            return Err(errors::TraitError::InvalidInput(format!(
+                "mime type {} is not allowed",
+                mime_type
+            )));
+        }
+        Ok(())
+    }
+
+    /// Number of chunks an attachment of size bytes will be split into; None if max_chunk_size is 0
+    pub fn chunk_count(&self, size: usize) -> Option<usize> {
+        let full_chunks = size.checked_div(self.max_chunk_size)?;
+        if size % self.max_chunk_size == 0 {
+            Some(full_chunks)
+        } else {
+            Some(full_chunks + 1)
+        }
+    }
+}
+
+/// Interface for storing chunked binary media which expressions can then reference by hash.
+/// Implementations should validate uploads against their attachment_limits both here and in validation callbacks.
+pub trait AttachmentsDao {
+    /// Split bytes into chunks of at most max_chunk_size and store them along with an Attachment entry
+    fn upload_chunked(bytes: Vec<u8>, mime_type: String) -> ExternResult<Attachment>;
+    fn get_attachment(hash: EntryHash) -> ExternResult<Option<Attachment>>;
+    /// Get and join all chunks of attachment
+    fn fetch_attachment(hash: EntryHash) -> ExternResult<Option<Vec<u8>>>;
+    fn attachment_limits() -> ExternResult<AttachmentLimits>;
+}
+
+/// How an anonymous expression is unlinked from the agent who created it
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum UnlinkabilityModel {
+    /// Every expression is authored by a fresh throwaway key which is never reused;
+    /// no two anonymous expressions can be linked to each other or to the agent
+    PerPostKey,
+    /// Expressions are authored by a key blinded per context; expressions in the same context
+    /// can be linked to each other but not to the agent or to their expressions in other contexts
+    ContextBlinded,
+}
+
+/// Steward controlled setting for anonymous expressions in a social context
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AnonymousPolicy {
+    pub allowed: bool,
+    pub model: UnlinkabilityModel,
+}
+
+/// Interface for creating expressions which cannot be attributed to the agent who created them.
+/// The creator field of returned expressions is the throwaway or blinded key as defined by the context's UnlinkabilityModel.
+///
+/// Implementations must not write anything to the real agent's source chain which links them to the anonymous expression.
+pub trait AnonymousExpressionDao {
+    /// Create an expression in context; fails if the context's AnonymousPolicy does not allow anonymous expressions
+    fn create_anonymous_expression(content: String, context: DnaHash) -> ExternResult<Expression>;
+    fn anonymous_policy(context: DnaHash) -> ExternResult<AnonymousPolicy>;
+    /// Set whether anonymous expressions are allowed in context; only callable by stewards of the context
+    fn set_anonymous_policy(context: DnaHash, policy: AnonymousPolicy) -> ExternResult<()>;
+}
+
+pub const MAX_TAG_LENGTH: usize = 64;
+
+/// Normalized hashtag. Tags are case folded to lowercase, have any leading '#' removed and must be
+/// between 1 and MAX_TAG_LENGTH characters made up of alphanumerics or '_'
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(try_from = "String", into = "String")]
+pub struct Tag(String);
+
+impl Tag {
+    pub fn new(raw: &str) -> Result<Tag, errors::TraitError> {
+        let normalized = raw.trim().trim_start_matches('#').to_lowercase();
+        let length = normalized.chars().count();
+        if length == 0 || length > MAX_TAG_LENGTH {
+            return Err(errors::TraitError::InvalidInput(format!(
+                "tag must be between 1 and {} characters",
+                MAX_TAG_LENGTH
+            )));
+        }
+        if !normalized.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(errors::TraitError::InvalidInput(format!(
+                "tag {} may only contain alphanumerics or '_'",
+                normalized
+            )));
+        }
+        Ok(Tag(normalized))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::convert::TryFrom<String> for Tag {
+    type Error = errors::TraitError;
+
+    fn try_from(raw: String) -> Result<Self, Self::Error> {
+        Tag::new(&raw)
+    }
+}
+
+impl From<Tag> for String {
+    fn from(tag: Tag) -> String {
+        tag.0
+    }
+}
+
+/// Interface for hashtags and mentions contained in expressions.
+/// Tags should be normalized via Tag::new so that the same tag is indexed identically across DNAs.
+pub trait TaggingDao {
+    fn tags_for(expression: AnyDhtHash) -> ExternResult<Vec<Tag>>;
+    fn expressions_by_tag(
+        tag: Tag,
+        page_size: usize,
+        cursor: Option<String>,
+        ordering: Ordering,
+    ) -> ExternResult<ExpressionPage>;
+    /// Get agents mentioned in an expression
+    fn mentions_in(expression: AnyDhtHash) -> ExternResult<Vec<Identity>>;
+}
+
+/// Address of a draft entry
+pub type DraftId = HeaderHash;
+
+/// Unpublished expression content private to the authoring agent
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Draft {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub id: DraftId,
+    pub content: String,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    /// Set when the draft has been scheduled for publication
+    pub publish_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Interface for drafting expressions before publishing them.
+/// Drafts should be stored as private entries so they are never gossiped to the DHT before publication.
+pub trait AuthoringDao {
+    fn save_draft(content: String) -> ExternResult<DraftId>;
+    fn update_draft(id: DraftId, content: String) -> ExternResult<DraftId>;
+    fn list_drafts() -> ExternResult<Vec<Draft>>;
+    fn delete_draft(id: DraftId) -> ExternResult<()>;
+    /// Publish draft as a public expression and remove the draft
+    fn publish_draft(id: DraftId) -> ExternResult<Expression>;
+    /// Save content as a draft which will be published at publish_at. Since a DNA cannot act while the agent is offline
+    /// publication happens on the first zome call/scheduled run after publish_at
+    fn schedule_expression(
+        content: String,
+        publish_at: chrono::DateTime<chrono::Utc>,
+    ) -> ExternResult<DraftId>;
+}
+
+/// Encrypted expression which is committed to now and revealed at a later time
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SealedExpression {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub address: HeaderHash,
+    pub ciphertext: Vec<u8>,
+    pub reveal_at: chrono::DateTime<chrono::Utc>,
+    /// Agents holding the key who are able to reveal the expression should the creator not do so
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))]
+    pub key_escrow: Vec<Identity>,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub creator: Identity,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Interface for commit-then-reveal expressions such as predictions or embargoed announcements.
+/// The published ciphertext acts as the commitment; it cannot be changed once created.
+pub trait SealedExpressionDao {
+    /// Publish ciphertext and privately send the decryption key to each of key_escrow
+    fn create_sealed_expression(
+        ciphertext: Vec<u8>,
+        reveal_at: chrono::DateTime<chrono::Utc>,
+        key_escrow: Vec<Identity>,
+    ) -> ExternResult<SealedExpression>;
+    fn get_sealed_expression(address: HeaderHash) -> ExternResult<Option<SealedExpression>>;
+}
+
+/// Protocol for revealing a SealedExpression. Host DNA validation must reject reveals before reveal_at,
+/// reveals by agents who are not the creator or in key_escrow, and keys which do not decrypt the ciphertext.
+pub trait RevealProtocolDao {
+    /// Publish key for sealed expression; returns the decrypted expression
+    fn reveal(sealed: HeaderHash, key: Vec<u8>) -> ExternResult<Expression>;
+    /// Get revealed expression for sealed expression; None if it has not been revealed yet
+    fn get_revealed(sealed: HeaderHash) -> ExternResult<Option<Expression>>;
+    /// Get sealed expressions current agent holds a key for which are now ready to be revealed
+    fn pending_reveals() -> ExternResult<Vec<SealedExpression>>;
+}
+
+/// Result of a trait method call as reported to Instrumentation
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum CallOutcome {
+    Success,
+    /// Call failed; carries a TraitError code or other error description
+    Failure(String),
+}
+
+/// Hook for happ operators to collect telemetry from trait implementations.
+/// Implementations of the traits in this crate should report each call, using the trait name (i.e "SocialGraphDao") as trait_id.
+/// Duration should be measured with sys_time as std::time::Instant is not available in WASM.
+pub trait Instrumentation {
+    fn on_call(
+        &self,
+        _trait_id: &str,
+        _method: &str,
+        _duration: std::time::Duration,
+        _outcome: &CallOutcome,
+    ) {
+    }
+}
+
+/// Instrumentation which discards all calls
+pub struct NoopInstrumentation;
+
+impl Instrumentation for NoopInstrumentation {}
+
+/// Entry in a MirrorDescriptor describing one segment of a public context
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MirrorSegmentRef {
+    pub id: String,
+    /// Hash of the serialized items of the segment, allowing a mirror to verify the fetched segment
+    pub content_hash: Vec<u8>,
+    pub item_count: usize,
+    pub from: chrono::DateTime<chrono::Utc>,
+    pub until: chrono::DateTime<chrono::Utc>,
+}
+
+/// Manifest of everything in a public context at the time of the snapshot.
+/// A mirror is complete once it holds every listed segment with a matching content_hash.
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MirrorDescriptor {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub dna: DnaHash,
+    pub generated_at: chrono::DateTime<chrono::Utc>,
+    pub segments: Vec<MirrorSegmentRef>,
+    pub total_items: usize,
+}
+
+/// Expressions of a single segment of a MirrorDescriptor
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MirrorSegment {
+    pub id: String,
+    pub items: Vec<Expression>,
+}
+
+/// Interface for mirroring a public context into read-only archives or web gateways.
+/// Should only be implemented by DNAs whose content is public; membraned DNAs must not expose it.
+pub trait MirrorableDao {
+    fn snapshot_manifest() -> ExternResult<MirrorDescriptor>;
+    fn fetch_segment(id: String) -> ExternResult<Option<MirrorSegment>>;
+}
+
+/// Category of an agent's data which may be relayed off-network
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ConsentScope {
+    Posts,
+    Profile,
+    Graph,
+}
+
+/// Agent's consent for a bridge to relay the given scopes of their data
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConsentRecord {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub address: HeaderHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub agent: Identity,
+    pub bridge_id: String,
+    pub scopes: Vec<ConsentScope>,
+    pub granted_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Interface for recording an agent's consent to data sharing with bridges (federation, export etc).
+/// Bridges must check has_consent for the relevant scope before relaying an agent's content off-network.
+pub trait ConsentDao {
+    fn grant_bridge_consent(bridge_id: String, scopes: Vec<ConsentScope>) -> ExternResult<ConsentRecord>;
+    /// Revoke all consent current agent has given to bridge
+    fn revoke(bridge_id: String) -> ExternResult<()>;
+    /// Get current agent's active consents
+    fn consents() -> ExternResult<Vec<ConsentRecord>>;
+    fn has_consent(agent: Identity, bridge_id: String, scope: ConsentScope) -> ExternResult<bool>;
+}
+
+/// Encryption scheme used for end-to-end encrypted expressions
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum EncryptionScheme {
+    /// libsodium crypto_box; X25519 key agreement with XSalsa20-Poly1305
+    X25519XSalsa20Poly1305,
+    /// Signal style double ratchet over X25519 with AES-256-GCM
+    DoubleRatchet,
+    Custom(String),
+}
+
+/// Encrypted session between two agents
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EncryptionSession {
+    pub id: String,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub with: Identity,
+    pub scheme: EncryptionScheme,
+    pub established_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Payload format of an encrypted expression
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CiphertextMeta {
+    pub scheme: EncryptionScheme,
+    pub session_id: String,
+    pub nonce: Vec<u8>,
+    pub ciphertext: Vec<u8>,
+}
+
+/// Interface for expression DNAs offering end-to-end encrypted private expressions.
+/// Encryption happens client side or in the sender's zome; the ciphertext is all that leaves the sender's source chain.
+pub trait EncryptedExpressionDao {
+    /// Perform key exchange with agent; returns existing session if one is already established
+    fn establish_session(with: Identity) -> ExternResult<EncryptionSession>;
+    fn send_encrypted(to: Identity, ciphertext_meta: CiphertextMeta) -> ExternResult<DeliveryStatus>;
+    /// Decrypt an encrypted expression received by current agent and return its content
+    fn decrypt(expression: AnyDhtHash) -> ExternResult<String>;
+    fn supported_schemes() -> ExternResult<Vec<EncryptionScheme>>;
+}
+
+/// Query for SearchDao. All set filters must match; empty terms match every expression
+#[derive(Serialize, Deserialize, Debug, Clone, Default, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SearchQuery {
+    /// Full text terms
+    pub terms: Vec<String>,
+    #[cfg_attr(feature = "schema", schemars(with = "Option<crate::schema::HoloHash>"))]
+    pub author: Option<Identity>,
+    pub tags: Vec<Tag>,
+    pub from: Option<chrono::DateTime<chrono::Utc>>,
+    pub until: Option<chrono::DateTime<chrono::Utc>>,
+    /// Mime types of expression content; empty matches any
+    pub content_types: Vec<String>,
+}
+
+/// Interface for searching expressions. How expressions are indexed is left to the implementation.
+pub trait SearchDao {
+    fn search_expressions(
+        query: SearchQuery,
+        page_size: usize,
+        cursor: Option<String>,
+        ordering: Ordering,
+    ) -> ExternResult<ExpressionPage>;
+}
+
+/// Interface for sharing one expression into multiple social contexts while keeping a single original.
+/// The original is referenced rather than duplicated so that provenance is preserved.
+pub trait CrossPostDao {
+    /// Post a reference to expression into the social context at context_dna; returns reference to the cross post
+    fn share_into(context_dna: DnaHash, expression: GlobalEntryRef)
+        -> ExternResult<GlobalEntryRef>;
+    /// Get cross posts of expression in every social context it has been shared into
+    fn shared_from(expression: GlobalEntryRef) -> ExternResult<Vec<GlobalEntryRef>>;
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ReportReason {
+    Spam,
+    Harassment,
+    HateSpeech,
+    Violence,
+    IllegalContent,
+    Misinformation,
+    Other(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Resolution {
+    /// Report was reviewed and no action was taken
+    Dismissed,
+    /// Reported content was removed
+    ContentRemoved,
+    /// Author of the reported content was removed from the context
+    AuthorRemoved,
+}
+
+/// Report of some entry made to the moderators of a DNA
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Report {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub id: HeaderHash,
+    pub entry: GlobalEntryRef,
+    pub reason: ReportReason,
+    pub details: Option<String>,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub reporter: Identity,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// None while the report is open
+    pub resolution: Option<Resolution>,
+}
+
+/// Interface for reporting and moderating content in expression and social context DNAs.
+/// Who counts as a moderator is defined by the host DNA, i.e via SocialContextPermissionsDao's DeleteOthers capability.
+pub trait ModerationDao {
+    fn report(
+        entry: GlobalEntryRef,
+        reason: ReportReason,
+        details: Option<String>,
+    ) -> ExternResult<Report>;
+    /// Get unresolved reports, oldest first; moderators only
+    fn open_reports(count: usize, page: usize) -> ExternResult<Vec<Report>>;
+    /// Resolve report; moderators only
+    fn resolve_report(id: HeaderHash, resolution: Resolution) -> ExternResult<Report>;
+    /// Remove content without it having been reported first; moderators only
+    fn remove_content(entry: GlobalEntryRef, reason: ReportReason) -> ExternResult<()>;
+}
+
+/// Question with a fixed set of options agents can vote on
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Poll {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub address: HeaderHash,
+    pub question: String,
+    pub options: Vec<String>,
+    /// Whether an agent may vote for more than one option
+    pub multiple_choice: bool,
+    pub closes_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub creator: Identity,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Votes per option of a poll, in the same order as Poll.options
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PollResults {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub poll: HeaderHash,
+    pub votes: Vec<(String, usize)>,
+    pub voters_count: usize,
+    pub closed: bool,
+}
+
+impl Poll {
+    /// AS2 Question for this poll including vote counts from results
+    pub fn to_question(&self, results: Option<&PollResults>) -> serde_json::Value {
+        let options: Vec<serde_json::Value> = self
+            .options
+            .iter()
+            .enumerate()
+            .map(|(index, option)| {
+                let votes = results
+                    .and_then(|results| results.votes.get(index))
+                    .map(|(_, votes)| *votes)
+                    .unwrap_or(0);
+                serde_json::json!({
+                    "type": "Note",
+                    "name": option,
+                    "replies": { "type": "Collection", "totalItems": votes },
+                })
+            })
+            .collect();
+        let mut question = serde_json::json!({
+            "type": "Question",
+            "id": format!("{}", self.address),
+            "content": self.question,
+            "attributedTo": format!("{}", self.creator),
+            "published": self.created_at.to_rfc3339(),
+        });
+        let options_key = if self.multiple_choice {
+            "anyOf"
+        } else {
+            "oneOf"
+        };
+        question[options_key] = serde_json::Value::Array(options);
+        if let Some(closes_at) = self.closes_at {
+            question["endTime"] = serde_json::Value::String(closes_at.to_rfc3339());
+        }
+        if let Some(results) = results {
+            question["votersCount"] = serde_json::json!(results.voters_count);
+        }
+        question
+    }
+}
+
+impl activitypub::ToActivityStreams for Poll {
+    fn to_activity_streams(&self) -> serde_json::Value {
+        self.to_question(None)
+    }
+}
+
+/// Interface for polls. Host DNA validation should reject votes after closes_at, votes for options
+/// not in the poll, and more than one vote per agent unless the poll is multiple_choice.
+pub trait PollsDao {
+    fn create_poll(
+        question: String,
+        options: Vec<String>,
+        multiple_choice: bool,
+        closes_at: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ExternResult<Poll>;
+    fn get_poll(poll: HeaderHash) -> ExternResult<Option<Poll>>;
+    /// Vote for option, given as an index into Poll.options
+    fn vote(poll: HeaderHash, option: usize) -> ExternResult<()>;
+    fn results(poll: HeaderHash) -> ExternResult<PollResults>;
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EventDetails {
+    pub name: String,
+    pub description: Option<String>,
+    pub starts_at: chrono::DateTime<chrono::Utc>,
+    pub ends_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub location: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Event {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub address: HeaderHash,
+    pub details: EventDetails,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub organizer: Identity,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl activitypub::ToActivityStreams for Event {
+    /// AS2 Event object for this event
+    fn to_activity_streams(&self) -> serde_json::Value {
+        let mut event = serde_json::json!({
+            "type": "Event",
+            "id": format!("{}", self.address),
+            "name": self.details.name,
+            "startTime": self.details.starts_at.to_rfc3339(),
+            "attributedTo": format!("{}", self.organizer),
+            "published": self.created_at.to_rfc3339(),
+        });
+        if let Some(description) = &self.details.description {
+            event["content"] = serde_json::Value::String(description.clone());
+        }
+        if let Some(ends_at) = self.details.ends_at {
+            event["endTime"] = serde_json::Value::String(ends_at.to_rfc3339());
+        }
+        if let Some(location) = &self.details.location {
+            event["location"] = serde_json::json!({ "type": "Place", "name": location });
+        }
+        event
+    }
+}
+
+impl activitypub::FromActivityStreams for EventDetails {
+    fn from_activity_streams(value: &serde_json::Value) -> Result<Self, errors::TraitError> {
+        let invalid = |property: &str| {
+            errors::TraitError::InvalidInput(format!(
+                "Event is missing or has invalid {}",
+                property
+            ))
+        };
+        let time =
+            |property: &str| -> Result<Option<chrono::DateTime<chrono::Utc>>, errors::TraitError> {
+                match value.get(property).and_then(|time| time.as_str()) {
+                    Some(time) => chrono::DateTime::parse_from_rfc3339(time)
+                        .map(|time| Some(time.with_timezone(&chrono::Utc)))
+                        .map_err(|_| invalid(property)),
+                    None => Ok(None),
+                }
+            };
+        if value.get("type").and_then(|kind| kind.as_str()) != Some("Event") {
+            return Err(invalid("type"));
+        }
+        Ok(EventDetails {
+            name: value
+                .get("name")
+                .and_then(|name| name.as_str())
+                .map(String::from)
+                .ok_or_else(|| invalid("name"))?,
+            description: value
+                .get("content")
+                .and_then(|content| content.as_str())
+                .map(String::from),
+            starts_at: time("startTime")?.ok_or_else(|| invalid("startTime"))?,
+            ends_at: time("endTime")?,
+            location: value
+                .get("location")
+                .and_then(|location| location.get("name").or(Some(location)))
+                .and_then(|location| location.as_str())
+                .map(String::from),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum RsvpStatus {
+    Going,
+    Maybe,
+    NotGoing,
+}
+
+impl RsvpStatus {
+    /// AS2 activity type an RSVP of this status is federated as, with the Event as its object
+    pub fn activity_kind(&self) -> &'static str {
+        match self {
+            RsvpStatus::Going => "Accept",
+            RsvpStatus::Maybe => "TentativeAccept",
+            RsvpStatus::NotGoing => "Reject",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Attendee {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub agent: Identity,
+    pub status: RsvpStatus,
+    pub responded_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Interface for community events and RSVPs
+pub trait EventsDao {
+    fn create_event(details: EventDetails) -> ExternResult<Event>;
+    fn get_event(event: HeaderHash) -> ExternResult<Option<Event>>;
+    /// Upcoming events ordered by start time
+    fn upcoming_events(count: usize, page: usize) -> ExternResult<Vec<Event>>;
+    /// Set current agent's RSVP; replaces any previous RSVP to the same event
+    fn rsvp(event: HeaderHash, status: RsvpStatus) -> ExternResult<()>;
+    fn attendees(event: HeaderHash) -> ExternResult<Vec<Attendee>>;
+}
+
+/// Agent key of a device acting on behalf of an identity
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Device {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub device_key: AgentPubKey,
+    pub name: String,
+    pub registered_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum KeyRotationKind {
+    DeviceAdded,
+    DeviceRevoked,
+}
+
+/// Entry recording a change to the set of devices acting as an identity. Other agents replay these
+/// in order to work out which device keys were valid for the identity at a given time
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct KeyRotationEvent {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub identity: Identity,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub device_key: AgentPubKey,
+    pub kind: KeyRotationKind,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+/// Interface for several devices (i.e phone and laptop agents) acting as a single identity.
+/// Must be called from a device which is itself registered, or from the identity's original agent.
+pub trait DeviceManagementDao {
+    fn register_device(device_key: AgentPubKey, name: String) -> ExternResult<KeyRotationEvent>;
+    fn revoke_device(device_key: AgentPubKey) -> ExternResult<KeyRotationEvent>;
+    /// Get devices currently registered to current agent's identity
+    fn my_devices() -> ExternResult<Vec<Device>>;
+    /// Get identity a device key acts on behalf of, if any
+    fn identity_of(device_key: AgentPubKey) -> ExternResult<Option<Identity>>;
+    fn key_rotation_events(identity: Identity) -> ExternResult<Vec<KeyRotationEvent>>;
+}
+
+/// Zome function a capability grant gives access to
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FnRef {
+    pub zome: String,
+    pub fn_name: String,
+}
+
+/// Capability granted by current agent to another agent. Named so as not to clash with hdk's CapGrant,
+/// which implementations will typically commit alongside it
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CapabilityGrant {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub grant_id: HeaderHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub assignee: Identity,
+    pub functions: Vec<FnRef>,
+    pub expiry: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Interface for standardized capability grants between agents, i.e for access to private endpoints of a DNA
+pub trait CapabilitiesDao {
+    fn grant(
+        assignee: Identity,
+        functions: Vec<FnRef>,
+        expiry: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ExternResult<CapabilityGrant>;
+    fn revoke(grant_id: HeaderHash) -> ExternResult<()>;
+    /// Get unexpired grants made by current agent
+    fn my_grants() -> ExternResult<Vec<CapabilityGrant>>;
+}
+
+/// Statement one agent makes about another
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Claim {
+    /// Agent is who they say they are; carries the name being vouched for
+    Identity(String),
+    /// Agent is a member of the social context at this DNA
+    Membership(#[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))] DnaHash),
+    Skill(String),
+    Custom {
+        kind: String,
+        value: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Attestation {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub id: HeaderHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub attester: Identity,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub about: Identity,
+    pub claim: Claim,
+    pub expiry: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Interface for agents attesting claims about other agents, for use by reputation and membrane logic
+pub trait AttestationsDao {
+    fn attest(
+        about: Identity,
+        claim: Claim,
+        expiry: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ExternResult<Attestation>;
+    /// Get unexpired, unrevoked attestations about agent
+    fn attestations_about(agent: Identity) -> ExternResult<Vec<Attestation>>;
+    /// Revoke an attestation made by current agent
+    fn revoke_attestation(id: HeaderHash) -> ExternResult<()>;
+}
+
+/// Event an agent is notified of, possibly produced by a different DNA to the one serving notifications
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Notification {
+    Mention {
+        #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+        by: Identity,
+        expression: GlobalEntryRef,
+    },
+    Reply {
+        #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+        by: Identity,
+        expression: GlobalEntryRef,
+        in_reply_to: GlobalEntryRef,
+    },
+    Reaction {
+        #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+        by: Identity,
+        expression: GlobalEntryRef,
+        reaction: String,
+    },
+    Follow {
+        #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+        by: Identity,
+    },
+    FriendRequest {
+        #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+        by: Identity,
+    },
+    ContextInvite {
+        #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+        by: Identity,
+        #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+        context: DnaHash,
+        #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+        invite: HeaderHash,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct NotificationItem {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub id: HeaderHash,
+    pub notification: Notification,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub seen: bool,
+}
+
+/// Interface aggregating notifications for current agent from all DNAs they take part in
+pub trait NotificationsDao {
+    /// Get notifications newest first
+    fn notifications(count: usize, page: usize) -> ExternResult<Vec<NotificationItem>>;
+    fn mark_seen(ids: Vec<HeaderHash>) -> ExternResult<()>;
+    fn unseen_count() -> ExternResult<usize>;
+}
+
+/// Why an expression appears in a feed
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum FeedReason {
+    /// Creator is followed by current agent
+    Followed,
+    /// Boosted by an agent current agent follows
+    BoostedBy(#[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))] Identity),
+    /// Posted in a social context current agent is a member of
+    InContext(#[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))] DnaHash),
+    /// Matches a subscription of current agent made via SubscriptionsDao
+    Subscribed(SubscriptionTarget),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FeedItem {
+    pub expression: Expression,
+    pub reason: FeedReason,
+}
+
+/// Page of a feed; see ExpressionPage for cursor semantics
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FeedPage {
+    pub items: Vec<FeedItem>,
+    pub next_cursor: Option<String>,
+}
+
+/// Interface for composing timelines out of the agents current agent follows and the social contexts they are in
+pub trait FeedDao {
+    /// Expressions from followed agents, their boosts, joined social contexts and subscriptions, newest first
+    fn home_timeline(page_size: usize, cursor: Option<String>) -> ExternResult<FeedPage>;
+    /// Expressions posted in a single social context, newest first
+    fn context_timeline(
+        context: DnaHash,
+        page_size: usize,
+        cursor: Option<String>,
+    ) -> ExternResult<FeedPage>;
+}
+
+/// Topic or social context an agent follows without following any of its authors
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SubscriptionTarget {
+    Tag(Tag),
+    Context(#[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))] DnaHash),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Subscription {
+    /// Address of the subscription entry; used to unsubscribe
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub address: HeaderHash,
+    pub target: SubscriptionTarget,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Interface for following tags and social contexts. Implementations of FeedDao should include matching expressions
+/// in home_timeline with FeedReason::Subscribed
+pub trait SubscriptionsDao {
+    fn subscribe_tag(tag: Tag) -> ExternResult<Subscription>;
+    fn subscribe_context(dna: DnaHash) -> ExternResult<Subscription>;
+    fn my_subscriptions() -> ExternResult<Vec<Subscription>>;
+    fn unsubscribe(subscription_address: HeaderHash) -> ExternResult<()>;
+}
+
+/// What a feed filter matches against
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum FilterMatch {
+    /// Case insensitive word or phrase in expression text
+    Keyword(String),
+    Author(#[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))] Identity),
+    Tag(Tag),
+    ContentType(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum FilterAction {
+    /// Leave matching expressions out of feeds entirely
+    Hide,
+    /// Show matching expressions behind a click-through warning naming the filter
+    Warn,
+}
+
+/// Preference of an agent to hide or warn about expressions in their feeds. Clients reading the same DNA should apply
+/// the same rules
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FilterRule {
+    pub matches: FilterMatch,
+    pub action: FilterAction,
+    /// None if the filter applies until removed
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl FilterRule {
+    pub fn is_expired(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        self.expires_at
+            .map(|expires_at| expires_at <= now)
+            .unwrap_or(false)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FeedFilter {
+    /// Address of the filter entry; used for removal
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub address: HeaderHash,
+    pub rule: FilterRule,
+}
+
+/// Interface for storing an agent's muted words and other feed filters in the DNA, so that they follow the agent
+/// between clients
+pub trait FeedFiltersDao {
+    fn add_filter(rule: FilterRule) -> ExternResult<FeedFilter>;
+    /// Get filters of current agent which have not expired
+    fn my_filters() -> ExternResult<Vec<FeedFilter>>;
+    fn remove_filter(filter_address: HeaderHash) -> ExternResult<()>;
+}
+
+/// Interface for saving expressions from any DNA for later. Bookmarks are private to the agent and are
+/// federated as an AS2 collection on the agent's actor only if the implementation chooses to
+pub trait BookmarksDao {
+    fn bookmark(entry: GlobalEntryRef) -> ExternResult<()>;
+    fn unbookmark(entry: GlobalEntryRef) -> ExternResult<()>;
+    /// Get bookmarks of current agent, most recently bookmarked first
+    fn my_bookmarks(count: usize, page: usize) -> ExternResult<Vec<GlobalEntryRef>>;
+}
+
+/// Private thread between a fixed set of agents
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Conversation {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub id: HeaderHash,
+    /// Every participant including the agent who started the conversation
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))]
+    pub participants: Vec<Identity>,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub started_by: Identity,
+    pub started_at: chrono::DateTime<chrono::Utc>,
+    pub last_message_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConversationMessage {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub id: HeaderHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub conversation: HeaderHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub author: Identity,
+    pub content: String,
+    pub sent_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Interface for threaded direct messaging between two or more agents
+pub trait ConversationsDao {
+    fn start_conversation(participants: Vec<Identity>) -> ExternResult<Conversation>;
+    /// Send content to every other participant of conversation
+    fn send_in_conversation(
+        conversation: HeaderHash,
+        content: String,
+    ) -> ExternResult<ConversationMessage>;
+    /// Get conversations current agent participates in, most recently active first
+    fn my_conversations(count: usize, page: usize) -> ExternResult<Vec<Conversation>>;
+    /// Get messages of conversation, newest first
+    fn conversation_messages(
+        conversation: HeaderHash,
+        count: usize,
+        page: usize,
+    ) -> ExternResult<Vec<ConversationMessage>>;
+}
+
+/// Group chat room within a social context
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Room {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub id: HeaderHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub context: DnaHash,
+    pub name: String,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub created_by: Identity,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RoomMessage {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub id: HeaderHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub room: HeaderHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub author: Identity,
+    pub content: String,
+    pub sent_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Ephemeral signal sent to room members while an agent is typing; never committed
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TypingSignal {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub room: HeaderHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub agent: Identity,
+    pub at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Interface for real-time-ish group chat inside a social context.
+/// Unlike expressions, room messages are expected to be short lived and fetched incrementally by time.
+pub trait RoomsDao {
+    fn create_room(context: DnaHash, name: String) -> ExternResult<Room>;
+    fn rooms(context: DnaHash) -> ExternResult<Vec<Room>>;
+    fn post_message(room: HeaderHash, content: String) -> ExternResult<RoomMessage>;
+    /// Get messages posted to room after timestamp, oldest first
+    fn messages_since(
+        room: HeaderHash,
+        timestamp: chrono::DateTime<chrono::Utc>,
+    ) -> ExternResult<Vec<RoomMessage>>;
+    /// Send a TypingSignal to members of room
+    fn typing(room: HeaderHash) -> ExternResult<()>;
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Status {
+    Online,
+    Away,
+    DoNotDisturb,
+    Custom {
+        emoji: Option<String>,
+        text: String,
+        /// Status reverts to Online after expiry
+        expiry: Option<chrono::DateTime<chrono::Utc>>,
+    },
+}
+
+/// Signal sent to an agent's contacts when their status changes
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PresenceSignal {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub agent: Identity,
+    pub status: Status,
+    pub at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Interface for showing availability of agents across apps
+pub trait PresenceDao {
+    /// Set current agent's status and send a PresenceSignal to their contacts
+    fn set_status(status: Status) -> ExternResult<()>;
+    /// Get last known status of agent; None if agent has never set one or is unreachable
+    fn status_of(agent: Identity) -> ExternResult<Option<Status>>;
+}
+
+/// How a membraned DNA decides which agents may join
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MembraneKind {
+    /// Anyone may join; no proof is required
+    Open,
+    /// Joining agents need a proof issued by an existing member
+    InviteOnly,
+    /// Joining agents need a proof issued by one of MembraneSpec.issuers
+    IssuerSigned,
+    Custom(String),
+}
+
+/// Requirements an agent must meet to join a DNA
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MembraneSpec {
+    pub kind: MembraneKind,
+    /// Input onboarding clients should ask the joining agent for
+    pub prompts: Vec<install::MembraneProofPrompt>,
+    /// Agents permitted to issue proofs when kind is IssuerSigned
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))]
+    pub issuers: Vec<Identity>,
+}
+
+/// Proof passed to the conductor when installing a DNA, allowing for_agent to join
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MembraneProof {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub dna: DnaHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub for_agent: Identity,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub issued_by: Identity,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Issuer's signature over dna, for_agent and expires_at
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub signature: Signature,
+}
+
+/// Interface for the membrane of a DNA, so that private DNAs can be joined the same way regardless of implementation
+pub trait MembraneDao {
+    fn membrane_requirements() -> ExternResult<MembraneSpec>;
+    /// Issue a proof allowing for_agent to join; fails with Unauthorized if current agent may not issue proofs
+    fn generate_proof(for_agent: Identity) -> ExternResult<MembraneProof>;
+    /// Check proof allows agent to join; used by the DNA's genesis self check and validation
+    fn verify_proof(agent: Identity, proof: MembraneProof) -> ExternResult<ValidationResult>;
+}
+
+/// Invitation for an agent to join a private DNA; i.e an inbox or outbox DNA membraned with MembraneKind::InviteOnly.
+/// token is a secret the invitee presents to accept, so it can also be shared out of band
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Invite {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub address: HeaderHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub inviter: Identity,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub invitee: Identity,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub target_dna: DnaHash,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub token: String,
+}
+
+impl Invite {
+    pub fn is_expired(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        self.expires_at
+            .map(|expires_at| expires_at <= now)
+            .unwrap_or(false)
+    }
+}
+
+/// Interface for onboarding agents into private DNAs. Implemented by a DNA both parties are already in, which
+/// issues the MembraneProof the invitee installs target_dna with
+pub trait InvitationsDao {
+    /// Invite agent to join dna; current agent must be permitted to generate membrane proofs for dna
+    fn create_invite(agent: Identity, dna: DnaHash) -> ExternResult<Invite>;
+    /// Accept an invite sent to current agent, returning the proof to install the invite's target_dna with.
+    /// Fails with NotFound if the invite has expired or been revoked
+    fn accept_invite(token: String) -> ExternResult<MembraneProof>;
+    /// Revoke an invite created by current agent which has not yet been accepted
+    fn revoke_invite(id: HeaderHash) -> ExternResult<()>;
+    /// Get unexpired invites sent to current agent which are yet to be accepted
+    fn pending_invites() -> ExternResult<Vec<Invite>>;
+}
+
+/// Co-author's countersignature over canonical::canonical_bytes of the proposal's (content, authors)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CoAuthorAcceptance {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub agent: Identity,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub signature: Signature,
+}
+
+/// Expression proposed by one agent to be published jointly with coauthors once they have all accepted
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CoAuthorshipProposal {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub address: HeaderHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub proposer: Identity,
+    pub content: String,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::schema::HoloHash>"))]
+    pub coauthors: Vec<Identity>,
+    pub acceptances: Vec<CoAuthorAcceptance>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl CoAuthorshipProposal {
+    /// Proposer followed by coauthors; the authors of the finalized expression
+    pub fn authors(&self) -> Vec<Identity> {
+        let mut authors = vec![self.proposer.clone()];
+        authors.extend(self.coauthors.iter().cloned());
+        authors
+    }
+
+    /// Whether every coauthor has accepted
+    pub fn is_complete(&self) -> bool {
+        self.coauthors.iter().all(|coauthor| {
+            self.acceptances
+                .iter()
+                .any(|acceptance| &acceptance.agent == coauthor)
+        })
+    }
+}
+
+/// Interface for expressions authored jointly by several agents; i.e collaborative posts and joint statements
+pub trait CoAuthoringDao {
+    fn propose_coauthored(
+        content: String,
+        coauthors: Vec<Identity>,
+    ) -> ExternResult<CoAuthorshipProposal>;
+    /// Countersign a proposal current agent is a coauthor of
+    fn accept_coauthorship(proposal: HeaderHash) -> ExternResult<CoAuthorshipProposal>;
+    /// Publish a complete proposal as an expression whose authors are every signer.
+    /// Fails with InvalidInput if any coauthor is yet to accept
+    fn finalize(proposal: HeaderHash) -> ExternResult<Expression>;
+}
 
-    fn get_outgoing(source: GlobalEntryRef, count: usize, page: usize) -> ExternResult<Vec<GlobalEntryRef>>;
-    fn get_incoming(target: GlobalEntryRef, count: usize, page: usize) -> ExternResult<Vec<GlobalEntryRef>>;
+/// Interface for organizations; i.e brands, collectives and DAOs. An organization is a social context (an AS2 Group)
+/// whose admins can post expressions attributed to it via OnBehalfOf
+pub trait OrgProfileDao: SocialContextDao {
+    fn add_admin(agent: Identity) -> ExternResult<()>;
+    /// Fails with InvalidInput if agent is the last admin
+    fn remove_admin(agent: Identity) -> ExternResult<()>;
+    fn admins() -> ExternResult<Vec<Identity>>;
+    /// Create an expression attributed to the organization; current agent must be an admin
+    fn post_as_org(content: String) -> ExternResult<Expression>;
 }
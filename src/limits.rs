@@ -0,0 +1,69 @@
+use hdk3::prelude::*;
+
+use crate::errors::TraitError;
+
+/// Maximum number of operations an agent may perform within a window of time
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RateLimit {
+    pub window: std::time::Duration,
+    pub max_ops: u32,
+}
+
+/// Size limits a DNA's validation rules enforce on each write; None if a dimension is unlimited
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Quota {
+    pub max_entry_bytes: Option<usize>,
+    pub max_attachments: Option<usize>,
+    pub max_links_per_entry: Option<usize>,
+}
+
+impl Quota {
+    /// Check a write against this quota before committing it, so that clients can reject it before validation does
+    pub fn check(
+        &self,
+        entry_bytes: usize,
+        attachments: usize,
+        links: usize,
+    ) -> Result<(), TraitError> {
+        let exceeds =
+            |value: usize, max: Option<usize>| max.map(|max| value > max).unwrap_or(false);
+        if exceeds(entry_bytes, self.max_entry_bytes) {
+            return Err(TraitError::InvalidInput(format!(
+                "entry of {} bytes exceeds maximum of {}",
+                entry_bytes,
+                self.max_entry_bytes.unwrap_or_default()
+            )));
+        }
+        if exceeds(attachments, self.max_attachments) {
+            return Err(TraitError::InvalidInput(format!(
+                "{} attachments exceeds maximum of {}",
+                attachments,
+                self.max_attachments.unwrap_or_default()
+            )));
+        }
+        if exceeds(links, self.max_links_per_entry) {
+            return Err(TraitError::InvalidInput(format!(
+                "{} links exceeds maximum of {} per entry",
+                links,
+                self.max_links_per_entry.unwrap_or_default()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Anti spam settings of a DNA
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DnaLimits {
+    /// None if writes are not rate limited
+    pub rate_limit: Option<RateLimit>,
+    pub quota: Quota,
+}
+
+/// Interface for clients to introspect a DNA's limits and pre-validate writes
+pub trait LimitsDao {
+    fn current_limits() -> ExternResult<DnaLimits>;
+}
@@ -0,0 +1,32 @@
+//! Shared card format for external links, so that a preview fetched by one client or bridge renders the same in
+//! every other.
+use hdk3::prelude::*;
+
+/// Metadata scraped from an external page, i.e from its OpenGraph tags
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UnfurlMetadata {
+    pub url: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    /// Url of the image to show on the card
+    pub image: Option<String>,
+    pub site_name: Option<String>,
+    pub fetched_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Link an expression shares, along with the preview its creator saw when posting it
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LinkContent {
+    pub url: String,
+    pub preview: Option<UnfurlMetadata>,
+}
+
+/// Interface for storing previews once per DNA rather than fetching them in every client. Previews are keyed by
+/// dedup::normalize_url of their url
+pub trait PreviewsDao {
+    /// Store preview, replacing any existing preview of the same url
+    fn store_preview(preview: UnfurlMetadata) -> ExternResult<EntryHash>;
+    fn get_preview(url: String) -> ExternResult<Option<UnfurlMetadata>>;
+}
@@ -0,0 +1,1029 @@
+//! In-memory reference implementations of the traits in this crate, for integration testing client code
+//! against semantically correct fakes. State is held per thread; call `set_current_agent` to choose which
+//! agent calls are made as and `reset` between tests.
+//!
+//! Errors are TraitErrors encoded with `TraitError::to_zome_error`, as a DNA built on this crate would return them.
+use hdk3::prelude::*;
+use holo_hash::DnaHash;
+use std::cell::{Cell, RefCell};
+
+use crate::errors::TraitError;
+use crate::{
+    group_by_dna, Audience, BatchResult, ContextInvite, DeliveryStatus, Expression, ExpressionDao,
+    ExpressionFilter, ExpressionPage, GlobalEntryRef, Identity, InterDNADao, InterDnaLink,
+    LanguageTag, LinkType, LinksByDna, Nonce, Ordering, PendingMessage, ReplyFilter,
+    SocialContextDao, SocialContextProfile, SocialGraphDao, Tombstone,
+};
+
+#[derive(Default)]
+struct SocialGraphState {
+    /// (follower, followed, by)
+    follows: Vec<(Identity, Identity, Option<String>)>,
+    /// (from, to)
+    friendship_requests: Vec<(Identity, Identity)>,
+    friendships: Vec<(Identity, Identity)>,
+}
+
+#[derive(Default)]
+struct SocialContextState {
+    profile: Option<SocialContextProfile>,
+    /// (author, expression)
+    posts: Vec<(Identity, GlobalEntryRef)>,
+    communication_methods: Vec<DnaHash>,
+    members: Vec<Identity>,
+    invites: Vec<ContextInvite>,
+    pinned: Vec<GlobalEntryRef>,
+}
+
+#[derive(Default)]
+struct InterDnaState {
+    links: Vec<InterDnaLink>,
+}
+
+struct StoredExpression {
+    sequence: u64,
+    expression: Expression,
+    content: String,
+    /// Expression this boosts
+    boost_of: Option<GlobalEntryRef>,
+    /// Expression this quotes
+    quote_of: Option<GlobalEntryRef>,
+}
+
+struct PrivateMessage {
+    sequence: u64,
+    recipient: Identity,
+    expression: Expression,
+    content: String,
+    read: bool,
+}
+
+#[derive(Default)]
+struct ExpressionState {
+    expressions: Vec<StoredExpression>,
+    tombstones: Vec<Tombstone>,
+    messages: Vec<PrivateMessage>,
+}
+
+thread_local! {
+    static CURRENT_AGENT: RefCell<Option<Identity>> = RefCell::new(None);
+    static NEXT_ADDRESS: Cell<u64> = Cell::new(0);
+    static SOCIAL_GRAPH: RefCell<SocialGraphState> = RefCell::new(SocialGraphState::default());
+    static SOCIAL_CONTEXT: RefCell<SocialContextState> = RefCell::new(SocialContextState::default());
+    static INTER_DNA: RefCell<InterDnaState> = RefCell::new(InterDnaState::default());
+    static EXPRESSIONS: RefCell<ExpressionState> = RefCell::new(ExpressionState::default());
+}
+
+/// Agent subsequent calls on this thread are made as
+pub fn set_current_agent(agent: Identity) {
+    CURRENT_AGENT.with(|current| *current.borrow_mut() = Some(agent));
+}
+
+/// Clear all state held by the reference implementations on this thread
+pub fn reset() {
+    CURRENT_AGENT.with(|current| *current.borrow_mut() = None);
+    NEXT_ADDRESS.with(|next| next.set(0));
+    SOCIAL_GRAPH.with(|state| *state.borrow_mut() = SocialGraphState::default());
+    SOCIAL_CONTEXT.with(|state| *state.borrow_mut() = SocialContextState::default());
+    INTER_DNA.with(|state| *state.borrow_mut() = InterDnaState::default());
+    EXPRESSIONS.with(|state| *state.borrow_mut() = ExpressionState::default());
+}
+
+fn current_agent() -> ExternResult<Identity> {
+    CURRENT_AGENT
+        .with(|current| current.borrow().clone())
+        .ok_or_else(|| {
+            HdkError::from(TraitError::Internal(String::from(
+                "no current agent set; call reference::set_current_agent first",
+            )))
+        })
+}
+
+/// Increasing sequence number of in-memory entries; also used to order and page expressions
+fn next_sequence() -> u64 {
+    NEXT_ADDRESS.with(|next| {
+        let sequence = next.get();
+        next.set(sequence + 1);
+        sequence
+    })
+}
+
+fn address(sequence: u64) -> HeaderHash {
+    let mut bytes = vec![0; 36];
+    bytes[..8].copy_from_slice(&sequence.to_be_bytes());
+    HeaderHash::from_raw_bytes(bytes)
+}
+
+/// Unique address for an in-memory entry
+fn next_address() -> HeaderHash {
+    address(next_sequence())
+}
+
+/// Page of items; empty if the page starts beyond usize::MAX
+fn paginate<T>(items: Vec<T>, count: usize, page: usize) -> Vec<T> {
+    match count.checked_mul(page) {
+        Some(skip) => items.into_iter().skip(skip).take(count).collect(),
+        None => Vec::new(),
+    }
+}
+
+fn push_unique(agents: &mut Vec<Identity>, agent: &Identity) {
+    if !agents.contains(agent) {
+        agents.push(agent.clone());
+    }
+}
+
+/// Breadth first walk used for nth level followers/following. next gives the neighbours of an agent;
+/// returns agents exactly n hops from start
+fn nth_level<F>(n: usize, start: Identity, next: F) -> Vec<Identity>
+where
+    F: Fn(&Identity) -> Vec<Identity>,
+{
+    let mut seen = vec![start.clone()];
+    let mut level = vec![start];
+    for _ in 0..n {
+        let mut next_level = Vec::new();
+        for agent in level.iter() {
+            for neighbour in next(agent) {
+                if !seen.contains(&neighbour) {
+                    push_unique(&mut seen, &neighbour);
+                    next_level.push(neighbour);
+                }
+            }
+        }
+        level = next_level;
+    }
+    level
+}
+
+fn by_matches(filter: &Option<String>, by: &Option<String>) -> bool {
+    filter.is_none() || filter == by
+}
+
+/// SocialGraphDao backed by thread local memory. A `by` of None matches follows made with any `by`
+pub struct InMemorySocialGraph;
+
+impl SocialGraphDao for InMemorySocialGraph {
+    fn my_followers(by: Option<String>) -> ExternResult<Vec<Identity>> {
+        Self::followers(current_agent()?, by)
+    }
+
+    fn followers(followed_agent: Identity, by: Option<String>) -> ExternResult<Vec<Identity>> {
+        Ok(SOCIAL_GRAPH.with(|state| {
+            let mut followers = Vec::new();
+            for (follower, followed, follow_by) in state.borrow().follows.iter() {
+                if followed == &followed_agent && by_matches(&by, follow_by) {
+                    push_unique(&mut followers, follower);
+                }
+            }
+            followers
+        }))
+    }
+
+    fn nth_level_followers(
+        n: usize,
+        followed_agent: Identity,
+        by: Option<String>,
+    ) -> ExternResult<Vec<Identity>> {
+        Ok(nth_level(n, followed_agent, |agent| {
+            Self::followers(agent.clone(), by.clone()).unwrap_or_default()
+        }))
+    }
+
+    fn my_followings(by: Option<String>) -> ExternResult<Vec<Identity>> {
+        Self::following(current_agent()?, by)
+    }
+
+    fn following(following_agent: Identity, by: Option<String>) -> ExternResult<Vec<Identity>> {
+        Ok(SOCIAL_GRAPH.with(|state| {
+            let mut following = Vec::new();
+            for (follower, followed, follow_by) in state.borrow().follows.iter() {
+                if follower == &following_agent && by_matches(&by, follow_by) {
+                    push_unique(&mut following, followed);
+                }
+            }
+            following
+        }))
+    }
+
+    fn nth_level_following(
+        n: usize,
+        following_agent: Identity,
+        by: Option<String>,
+    ) -> ExternResult<Vec<Identity>> {
+        Ok(nth_level(n, following_agent, |agent| {
+            Self::following(agent.clone(), by.clone()).unwrap_or_default()
+        }))
+    }
+
+    fn follow(target_agent: Identity, by: Option<String>) -> ExternResult<()> {
+        let follow = (current_agent()?, target_agent, by);
+        SOCIAL_GRAPH.with(|state| {
+            let mut state = state.borrow_mut();
+            if !state.follows.contains(&follow) {
+                state.follows.push(follow);
+            }
+        });
+        Ok(())
+    }
+
+    fn unfollow(target_agent: Identity, by: Option<String>) -> ExternResult<()> {
+        let me = current_agent()?;
+        SOCIAL_GRAPH.with(|state| {
+            state
+                .borrow_mut()
+                .follows
+                .retain(|(follower, followed, follow_by)| {
+                    !(follower == &me && followed == &target_agent && by_matches(&by, follow_by))
+                })
+        });
+        Ok(())
+    }
+
+    fn my_friends() -> ExternResult<Vec<Identity>> {
+        Self::friends_of(current_agent()?)
+    }
+
+    fn friends_of(agent: Identity) -> ExternResult<Vec<Identity>> {
+        Ok(SOCIAL_GRAPH.with(|state| {
+            state
+                .borrow()
+                .friendships
+                .iter()
+                .filter_map(|(a, b)| match (a == &agent, b == &agent) {
+                    (true, _) => Some(b.clone()),
+                    (_, true) => Some(a.clone()),
+                    _ => None,
+                })
+                .collect()
+        }))
+    }
+
+    /// Requesting friendship of an agent who has already requested yours makes you friends
+    fn request_friendship(target_agent: Identity) -> ExternResult<()> {
+        let me = current_agent()?;
+        if Self::friends_of(me.clone())?.contains(&target_agent) {
+            return Ok(());
+        }
+        SOCIAL_GRAPH.with(|state| {
+            let mut state = state.borrow_mut();
+            let reciprocal = (target_agent.clone(), me.clone());
+            if state.friendship_requests.contains(&reciprocal) {
+                state
+                    .friendship_requests
+                    .retain(|request| request != &reciprocal);
+                state.friendships.push((me, target_agent));
+            } else if !state
+                .friendship_requests
+                .contains(&(me.clone(), target_agent.clone()))
+            {
+                state.friendship_requests.push((me, target_agent));
+            }
+        });
+        Ok(())
+    }
+
+    fn decline_friendship(target_agent: Identity) -> ExternResult<()> {
+        let request = (target_agent, current_agent()?);
+        SOCIAL_GRAPH.with(|state| {
+            state
+                .borrow_mut()
+                .friendship_requests
+                .retain(|existing| existing != &request)
+        });
+        Ok(())
+    }
+
+    fn incoming_friendship_requests() -> ExternResult<Vec<Identity>> {
+        let me = current_agent()?;
+        Ok(SOCIAL_GRAPH.with(|state| {
+            state
+                .borrow()
+                .friendship_requests
+                .iter()
+                .filter(|(_, to)| to == &me)
+                .map(|(from, _)| from.clone())
+                .collect()
+        }))
+    }
+
+    fn outgoing_friendship_requests() -> ExternResult<Vec<Identity>> {
+        let me = current_agent()?;
+        Ok(SOCIAL_GRAPH.with(|state| {
+            state
+                .borrow()
+                .friendship_requests
+                .iter()
+                .filter(|(from, _)| from == &me)
+                .map(|(_, to)| to.clone())
+                .collect()
+        }))
+    }
+
+    fn drop_friendship(target_agent: Identity) -> ExternResult<()> {
+        let me = current_agent()?;
+        SOCIAL_GRAPH.with(|state| {
+            state.borrow_mut().friendships.retain(|(a, b)| {
+                !((a == &me && b == &target_agent) || (a == &target_agent && b == &me))
+            })
+        });
+        Ok(())
+    }
+}
+
+/// SocialContextDao backed by thread local memory. Membership is open and role based restrictions
+/// (i.e on pinning) are not enforced
+pub struct InMemorySocialContext;
+
+impl InMemorySocialContext {
+    /// Set the profile returned by get_context_info
+    pub fn configure(profile: SocialContextProfile) {
+        SOCIAL_CONTEXT.with(|state| state.borrow_mut().profile = Some(profile));
+    }
+}
+
+impl SocialContextDao for InMemorySocialContext {
+    fn post(expression_ref: GlobalEntryRef) -> ExternResult<()> {
+        let author = current_agent()?;
+        SOCIAL_CONTEXT.with(|state| state.borrow_mut().posts.push((author, expression_ref)));
+        Ok(())
+    }
+
+    fn register_communication_method(dna_address: DnaHash) -> ExternResult<()> {
+        SOCIAL_CONTEXT.with(|state| {
+            let mut state = state.borrow_mut();
+            if !state.communication_methods.contains(&dna_address) {
+                state.communication_methods.push(dna_address);
+            }
+        });
+        Ok(())
+    }
+
+    fn writable() -> bool {
+        match current_agent() {
+            Ok(agent) => Self::is_member(agent).unwrap_or(false),
+            Err(_) => false,
+        }
+    }
+
+    fn read_communications(
+        by_dna: Option<DnaHash>,
+        by_agent: Option<Identity>,
+        count: usize,
+        page: usize,
+    ) -> ExternResult<Vec<GlobalEntryRef>> {
+        let posts = SOCIAL_CONTEXT.with(|state| {
+            state
+                .borrow()
+                .posts
+                .iter()
+                .filter(|(author, expression)| {
+                    by_dna.as_ref().map_or(true, |dna| &expression.dna == dna)
+                        && by_agent.as_ref().map_or(true, |agent| author == agent)
+                })
+                .map(|(_, expression)| expression.clone())
+                .collect()
+        });
+        Ok(paginate(posts, count, page))
+    }
+
+    fn get_communication_methods(count: usize, page: usize) -> ExternResult<Vec<DnaHash>> {
+        let methods = SOCIAL_CONTEXT.with(|state| state.borrow().communication_methods.clone());
+        Ok(paginate(methods, count, page))
+    }
+
+    fn members(count: usize, page: usize) -> ExternResult<Option<Vec<Identity>>> {
+        let members = SOCIAL_CONTEXT.with(|state| state.borrow().members.clone());
+        Ok(Some(paginate(members, count, page)))
+    }
+
+    fn join() -> ExternResult<()> {
+        let agent = current_agent()?;
+        SOCIAL_CONTEXT.with(|state| push_unique(&mut state.borrow_mut().members, &agent));
+        Ok(())
+    }
+
+    fn leave() -> ExternResult<()> {
+        let agent = current_agent()?;
+        SOCIAL_CONTEXT.with(|state| state.borrow_mut().members.retain(|member| member != &agent));
+        Ok(())
+    }
+
+    fn is_member(agent: Identity) -> ExternResult<bool> {
+        Ok(SOCIAL_CONTEXT.with(|state| state.borrow().members.contains(&agent)))
+    }
+
+    fn invite(agent: Identity) -> ExternResult<ContextInvite> {
+        let invite = ContextInvite {
+            address: next_address(),
+            inviter: current_agent()?,
+            invitee: agent,
+            created_at: chrono::Utc::now(),
+        };
+        SOCIAL_CONTEXT.with(|state| state.borrow_mut().invites.push(invite.clone()));
+        Ok(invite)
+    }
+
+    fn accept_invite(invite: HeaderHash) -> ExternResult<()> {
+        Self::decline_invite(invite)?;
+        Self::join()
+    }
+
+    fn decline_invite(invite: HeaderHash) -> ExternResult<()> {
+        let agent = current_agent()?;
+        SOCIAL_CONTEXT.with(|state| {
+            let mut state = state.borrow_mut();
+            let position = state
+                .invites
+                .iter()
+                .position(|existing| existing.address == invite && existing.invitee == agent)
+                .ok_or_else(|| HdkError::from(TraitError::NotFound))?;
+            state.invites.remove(position);
+            Ok(())
+        })
+    }
+
+    fn pending_invites() -> ExternResult<Vec<ContextInvite>> {
+        let agent = current_agent()?;
+        Ok(SOCIAL_CONTEXT.with(|state| {
+            state
+                .borrow()
+                .invites
+                .iter()
+                .filter(|invite| invite.invitee == agent)
+                .cloned()
+                .collect()
+        }))
+    }
+
+    fn get_context_info() -> ExternResult<SocialContextProfile> {
+        SOCIAL_CONTEXT
+            .with(|state| state.borrow().profile.clone())
+            .ok_or_else(|| {
+                HdkError::from(TraitError::Internal(String::from(
+                    "context not configured; call InMemorySocialContext::configure first",
+                )))
+            })
+    }
+
+    fn update_context_info(profile: SocialContextProfile) -> ExternResult<SocialContextProfile> {
+        let created_by = Self::get_context_info()?.created_by;
+        let profile = SocialContextProfile {
+            created_by,
+            ..profile
+        };
+        Self::configure(profile.clone());
+        Ok(profile)
+    }
+
+    fn pin_expression(entry: GlobalEntryRef) -> ExternResult<()> {
+        SOCIAL_CONTEXT.with(|state| {
+            let mut state = state.borrow_mut();
+            state.pinned.retain(|pinned| pinned != &entry);
+            state.pinned.insert(0, entry);
+        });
+        Ok(())
+    }
+
+    fn unpin_expression(entry: GlobalEntryRef) -> ExternResult<()> {
+        SOCIAL_CONTEXT.with(|state| state.borrow_mut().pinned.retain(|pinned| pinned != &entry));
+        Ok(())
+    }
+
+    fn pinned() -> ExternResult<Vec<GlobalEntryRef>> {
+        Ok(SOCIAL_CONTEXT.with(|state| state.borrow().pinned.clone()))
+    }
+}
+
+/// InterDNADao backed by thread local memory. No link policy is enforced so proof_of_work is ignored
+pub struct InMemoryInterDna;
+
+impl InMemoryInterDna {
+    fn links_where<F>(filter: F) -> Vec<InterDnaLink>
+    where
+        F: Fn(&InterDnaLink) -> bool,
+    {
+        INTER_DNA.with(|state| {
+            state
+                .borrow()
+                .links
+                .iter()
+                .filter(|link| filter(link))
+                .cloned()
+                .collect()
+        })
+    }
+}
+
+fn link_type_matches(filter: &Option<LinkType>, link_type: &LinkType) -> bool {
+    filter.as_ref().map_or(true, |filter| filter == link_type)
+}
+
+impl InterDNADao for InMemoryInterDna {
+    fn create_link(
+        source: GlobalEntryRef,
+        target: GlobalEntryRef,
+        link_type: LinkType,
+        metadata: Option<String>,
+        _proof_of_work: Option<Nonce>,
+    ) -> ExternResult<InterDnaLink> {
+        let link = InterDnaLink {
+            address: next_address(),
+            source,
+            target,
+            link_type,
+            metadata,
+            created_at: chrono::Utc::now(),
+            author: current_agent()?,
+        };
+        INTER_DNA.with(|state| state.borrow_mut().links.push(link.clone()));
+        Ok(link)
+    }
+
+    fn create_links(
+        links: Vec<(GlobalEntryRef, GlobalEntryRef, LinkType, Option<Nonce>)>,
+    ) -> ExternResult<BatchResult> {
+        // Only failure is a missing current agent, which fails every link; check up front so the batch stays atomic
+        current_agent()?;
+        let count = links.len();
+        for (source, target, link_type, proof_of_work) in links {
+            Self::create_link(source, target, link_type, None, proof_of_work)?;
+        }
+        Ok(BatchResult::AllCreated { count })
+    }
+
+    fn remove_link(source: GlobalEntryRef, target: GlobalEntryRef) -> ExternResult<InterDnaLink> {
+        INTER_DNA.with(|state| {
+            let mut state = state.borrow_mut();
+            let position = state
+                .links
+                .iter()
+                .position(|link| link.source == source && link.target == target)
+                .ok_or_else(|| HdkError::from(TraitError::NotFound))?;
+            Ok(state.links.remove(position))
+        })
+    }
+
+    fn get_outgoing(
+        source: GlobalEntryRef,
+        link_type: Option<LinkType>,
+        count: usize,
+        page: usize,
+    ) -> ExternResult<Vec<GlobalEntryRef>> {
+        let targets = Self::links_where(|link| {
+            link.source == source && link_type_matches(&link_type, &link.link_type)
+        })
+        .into_iter()
+        .map(|link| link.target)
+        .collect();
+        Ok(paginate(targets, count, page))
+    }
+
+    fn get_incoming(
+        target: GlobalEntryRef,
+        link_type: Option<LinkType>,
+        count: usize,
+        page: usize,
+    ) -> ExternResult<Vec<GlobalEntryRef>> {
+        let sources = Self::links_where(|link| {
+            link.target == target && link_type_matches(&link_type, &link.link_type)
+        })
+        .into_iter()
+        .map(|link| link.source)
+        .collect();
+        Ok(paginate(sources, count, page))
+    }
+
+    fn get_outgoing_multi(
+        source: GlobalEntryRef,
+        dnas: Vec<DnaHash>,
+        link_type: Option<LinkType>,
+        count: usize,
+        page: usize,
+    ) -> ExternResult<Vec<LinksByDna>> {
+        let targets = Self::links_where(|link| {
+            link.source == source
+                && dnas.contains(&link.target.dna)
+                && link_type_matches(&link_type, &link.link_type)
+        })
+        .into_iter()
+        .map(|link| link.target)
+        .collect();
+        Ok(group_by_dna(paginate(targets, count, page)))
+    }
+
+    fn get_all_outgoing(
+        source: GlobalEntryRef,
+        count: usize,
+        page: usize,
+    ) -> ExternResult<Vec<LinksByDna>> {
+        Ok(group_by_dna(Self::get_outgoing(source, None, count, page)?))
+    }
+
+    fn count_outgoing(source: GlobalEntryRef, link_type: Option<LinkType>) -> ExternResult<usize> {
+        Ok(Self::links_where(|link| {
+            link.source == source && link_type_matches(&link_type, &link.link_type)
+        })
+        .len())
+    }
+
+    fn count_incoming(target: GlobalEntryRef, link_type: Option<LinkType>) -> ExternResult<usize> {
+        Ok(Self::links_where(|link| {
+            link.target == target && link_type_matches(&link_type, &link.link_type)
+        })
+        .len())
+    }
+
+    fn translations_of(
+        expression: GlobalEntryRef,
+    ) -> ExternResult<Vec<(LanguageTag, GlobalEntryRef)>> {
+        Ok(Self::links_where(|link| link.source == expression)
+            .into_iter()
+            .filter_map(|link| match link.link_type {
+                LinkType::Translation { language } => Some((language, link.target)),
+                _ => None,
+            })
+            .collect())
+    }
+}
+
+/// DNA expressions made by InMemoryExpression are attributed to
+fn expression_dna() -> DnaHash {
+    DnaHash::from_raw_bytes(vec![0; 36])
+}
+
+/// Expression wrapping a synthetic Element: a Dna header whose hash is the expression's address and no entry.
+/// Content is held alongside and can be read with `InMemoryExpression::content_of`
+fn new_expression(audience: Audience) -> ExternResult<(u64, Expression)> {
+    let creator = current_agent()?;
+    let sequence = next_sequence();
+    let created_at = chrono::Utc::now();
+    let header = Header::Dna(Dna {
+        author: creator.clone(),
+        timestamp: Timestamp(created_at.timestamp(), created_at.timestamp_subsec_nanos()),
+        hash: expression_dna(),
+    });
+    let header = HeaderHashed::with_pre_hashed(header, address(sequence));
+    let expression = Expression {
+        expression: Element::new(
+            SignedHeaderHashed::with_presigned(header, Signature(vec![0; 64])),
+            None,
+        ),
+        expression_dna: expression_dna(),
+        creator,
+        created_at,
+        signature: None,
+        sensitivity: None,
+        language: None,
+        audience,
+        authors: Vec::new(),
+        on_behalf_of: None,
+    };
+    Ok((sequence, expression))
+}
+
+fn expression_address(expression: &Expression) -> AnyDhtHash {
+    AnyDhtHash::from(expression.expression.header_address().clone())
+}
+
+/// Expressions made by InMemoryExpression are untagged plain text which do not reply to anything
+fn filter_allows(filter: &ExpressionFilter, expression: &Expression) -> bool {
+    (filter.content_types.is_empty()
+        || filter
+            .content_types
+            .iter()
+            .any(|content_type| content_type == "text/plain"))
+        && filter.tags.is_empty()
+        && filter.replies != ReplyFilter::OnlyReplies
+        && filter.language.allows(expression.language.as_ref())
+        && filter
+            .since
+            .map_or(true, |since| expression.created_at >= since)
+        && filter
+            .until
+            .map_or(true, |until| expression.created_at < until)
+}
+
+/// Page of expressions, which must be in increasing sequence order. The cursor is the sequence of the last
+/// expression on the previous page so that expressions added since do not shift pages
+fn cursor_page(
+    mut expressions: Vec<(u64, Expression)>,
+    newest_first: bool,
+    page_size: usize,
+    cursor: Option<String>,
+) -> ExternResult<ExpressionPage> {
+    let total = expressions.len();
+    let after = match cursor {
+        Some(cursor) => Some(cursor.parse::<u64>().map_err(|_| {
+            TraitError::InvalidInput(format!(
+                "{} is not a cursor returned by this implementation",
+                cursor
+            ))
+        })?),
+        None => None,
+    };
+    if newest_first {
+        expressions.reverse();
+    }
+    let mut remaining: Vec<(u64, Expression)> = expressions
+        .into_iter()
+        .filter(|(sequence, _)| match after {
+            Some(after) if newest_first => *sequence < after,
+            Some(after) => *sequence > after,
+            None => true,
+        })
+        .collect();
+    let has_more = remaining.len() > page_size;
+    remaining.truncate(page_size);
+    let next_cursor = match remaining.last() {
+        Some((sequence, _)) if has_more => Some(sequence.to_string()),
+        _ => None,
+    };
+    Ok(ExpressionPage {
+        items: remaining
+            .into_iter()
+            .map(|(_, expression)| expression)
+            .collect(),
+        next_cursor,
+        total_hint: Some(total),
+    })
+}
+
+/// ExpressionDao backed by thread local memory. Expressions are not signed, private expressions are delivered
+/// immediately so the outbox is always empty and no ReadReceipt signals are sent
+pub struct InMemoryExpression;
+
+impl InMemoryExpression {
+    /// Content an expression or private expression was created with
+    pub fn content_of(address: AnyDhtHash) -> Option<String> {
+        EXPRESSIONS.with(|state| {
+            let state = state.borrow();
+            state
+                .expressions
+                .iter()
+                .find(|stored| expression_address(&stored.expression) == address)
+                .map(|stored| stored.content.clone())
+                .or_else(|| {
+                    state
+                        .messages
+                        .iter()
+                        .find(|message| expression_address(&message.expression) == address)
+                        .map(|message| message.content.clone())
+                })
+        })
+    }
+
+    fn store(
+        content: String,
+        audience: Audience,
+        boost_of: Option<GlobalEntryRef>,
+        quote_of: Option<GlobalEntryRef>,
+    ) -> ExternResult<Expression> {
+        let (sequence, expression) = new_expression(audience)?;
+        EXPRESSIONS.with(|state| {
+            state.borrow_mut().expressions.push(StoredExpression {
+                sequence,
+                expression: expression.clone(),
+                content,
+                boost_of,
+                quote_of,
+            })
+        });
+        Ok(expression)
+    }
+
+    fn expressions_where<F>(filter: F) -> Vec<(u64, Expression)>
+    where
+        F: Fn(&StoredExpression) -> bool,
+    {
+        EXPRESSIONS.with(|state| {
+            state
+                .borrow()
+                .expressions
+                .iter()
+                .filter(|stored| filter(stored))
+                .map(|stored| (stored.sequence, stored.expression.clone()))
+                .collect()
+        })
+    }
+
+    /// Private expressions sent to the current agent, oldest first
+    fn my_messages(from: Option<Identity>) -> ExternResult<Vec<(u64, Expression)>> {
+        let me = current_agent()?;
+        Ok(EXPRESSIONS.with(|state| {
+            state
+                .borrow()
+                .messages
+                .iter()
+                .filter(|message| {
+                    message.recipient == me
+                        && from
+                            .as_ref()
+                            .map_or(true, |from| &message.expression.creator == from)
+                })
+                .map(|message| (message.sequence, message.expression.clone()))
+                .collect()
+        }))
+    }
+}
+
+impl ExpressionDao for InMemoryExpression {
+    fn create_public_expression(content: String, audience: Audience) -> ExternResult<Expression> {
+        Self::store(content, audience, None, None)
+    }
+
+    fn get_by_author(
+        author: Identity,
+        filter: ExpressionFilter,
+        page_size: usize,
+        cursor: Option<String>,
+    ) -> ExternResult<ExpressionPage> {
+        let expressions = Self::expressions_where(|stored| {
+            stored.expression.creator == author && filter_allows(&filter, &stored.expression)
+        });
+        cursor_page(
+            expressions,
+            filter.ordering == Ordering::NewestFirst,
+            page_size,
+            cursor,
+        )
+    }
+
+    fn get_expression_by_address(address: AnyDhtHash) -> ExternResult<Option<Expression>> {
+        Ok(
+            Self::expressions_where(|stored| expression_address(&stored.expression) == address)
+                .pop()
+                .map(|(_, expression)| expression),
+        )
+    }
+
+    fn get_many_by_address(addresses: Vec<AnyDhtHash>) -> ExternResult<Vec<Option<Expression>>> {
+        addresses
+            .into_iter()
+            .map(Self::get_expression_by_address)
+            .collect()
+    }
+
+    fn delete_expression(address: AnyDhtHash) -> ExternResult<Tombstone> {
+        let me = current_agent()?;
+        EXPRESSIONS.with(|state| {
+            let mut state = state.borrow_mut();
+            let position = state
+                .expressions
+                .iter()
+                .position(|stored| expression_address(&stored.expression) == address)
+                .ok_or_else(|| HdkError::from(TraitError::NotFound))?;
+            if state.expressions[position].expression.creator != me {
+                return Err(HdkError::from(TraitError::Unauthorized));
+            }
+            state.expressions.remove(position);
+            let tombstone = Tombstone {
+                expression: address,
+                expression_dna: expression_dna(),
+                deleted_by: me,
+                deleted_at: chrono::Utc::now(),
+            };
+            state.tombstones.push(tombstone.clone());
+            Ok(tombstone)
+        })
+    }
+
+    fn is_deleted(address: AnyDhtHash) -> ExternResult<bool> {
+        Ok(EXPRESSIONS.with(|state| {
+            state
+                .borrow()
+                .tombstones
+                .iter()
+                .any(|tombstone| tombstone.expression == address)
+        }))
+    }
+
+    fn tombstones_since(timestamp: chrono::DateTime<chrono::Utc>) -> ExternResult<Vec<Tombstone>> {
+        Ok(EXPRESSIONS.with(|state| {
+            state
+                .borrow()
+                .tombstones
+                .iter()
+                .filter(|tombstone| tombstone.deleted_at >= timestamp)
+                .cloned()
+                .collect()
+        }))
+    }
+
+    fn boost(expression: GlobalEntryRef) -> ExternResult<Expression> {
+        Self::store(String::new(), Audience::Public, Some(expression), None)
+    }
+
+    fn quote(expression: GlobalEntryRef, commentary: String) -> ExternResult<Expression> {
+        Self::store(commentary, Audience::Public, None, Some(expression))
+    }
+
+    fn boosts_of(
+        expression: GlobalEntryRef,
+        count: usize,
+        page: usize,
+    ) -> ExternResult<Vec<Expression>> {
+        let boosts =
+            Self::expressions_where(|stored| stored.boost_of.as_ref() == Some(&expression));
+        Ok(paginate(boosts, count, page)
+            .into_iter()
+            .map(|(_, boost)| boost)
+            .collect())
+    }
+
+    fn quotes_of(
+        expression: GlobalEntryRef,
+        count: usize,
+        page: usize,
+    ) -> ExternResult<Vec<Expression>> {
+        let quotes =
+            Self::expressions_where(|stored| stored.quote_of.as_ref() == Some(&expression));
+        Ok(paginate(quotes, count, page)
+            .into_iter()
+            .map(|(_, quote)| quote)
+            .collect())
+    }
+
+    fn send_private(to: Identity, content: String) -> ExternResult<DeliveryStatus> {
+        let (sequence, expression) = new_expression(Audience::Agents(vec![to.clone()]))?;
+        EXPRESSIONS.with(|state| {
+            state.borrow_mut().messages.push(PrivateMessage {
+                sequence,
+                recipient: to,
+                expression,
+                content,
+                read: false,
+            })
+        });
+        Ok(DeliveryStatus::Delivered)
+    }
+
+    fn pending_outbox() -> ExternResult<Vec<PendingMessage>> {
+        Ok(Vec::new())
+    }
+
+    fn inbox(
+        from: Option<Identity>,
+        page_size: usize,
+        page_number: usize,
+    ) -> ExternResult<Vec<Expression>> {
+        let mut messages = Self::my_messages(from)?;
+        messages.reverse();
+        Ok(paginate(messages, page_size, page_number)
+            .into_iter()
+            .map(|(_, expression)| expression)
+            .collect())
+    }
+
+    fn inbox_paged(
+        from: Option<Identity>,
+        page_size: usize,
+        cursor: Option<String>,
+    ) -> ExternResult<ExpressionPage> {
+        cursor_page(Self::my_messages(from)?, true, page_size, cursor)
+    }
+
+    fn mark_read(expression: AnyDhtHash) -> ExternResult<()> {
+        let me = current_agent()?;
+        EXPRESSIONS.with(|state| {
+            let mut state = state.borrow_mut();
+            let message = state
+                .messages
+                .iter_mut()
+                .find(|message| {
+                    message.recipient == me && expression_address(&message.expression) == expression
+                })
+                .ok_or_else(|| HdkError::from(TraitError::NotFound))?;
+            message.read = true;
+            Ok(())
+        })
+    }
+
+    fn unread_count() -> ExternResult<usize> {
+        let me = current_agent()?;
+        Ok(EXPRESSIONS.with(|state| {
+            state
+                .borrow()
+                .messages
+                .iter()
+                .filter(|message| message.recipient == me && !message.read)
+                .count()
+        }))
+    }
+
+    fn delete_from_inbox(expression: AnyDhtHash) -> ExternResult<()> {
+        let me = current_agent()?;
+        EXPRESSIONS.with(|state| {
+            let mut state = state.borrow_mut();
+            let position = state
+                .messages
+                .iter()
+                .position(|message| {
+                    message.recipient == me && expression_address(&message.expression) == expression
+                })
+                .ok_or_else(|| HdkError::from(TraitError::NotFound))?;
+            state.messages.remove(position);
+            Ok(())
+        })
+    }
+}
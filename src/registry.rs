@@ -0,0 +1,178 @@
+use hdk3::prelude::*;
+
+/// Trait from this crate implemented by a DNA, at a given interface version. methods are the zome function
+/// names the DNA exposes for the trait
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TraitDescriptor {
+    pub name: String,
+    pub version: u32,
+    pub methods: Vec<String>,
+}
+
+const CORE_TRAITS: &[(&str, u32, &[&str])] = &[
+    (
+        "SocialGraphDao",
+        1,
+        &[
+            "my_followers",
+            "followers",
+            "nth_level_followers",
+            "my_followings",
+            "following",
+            "nth_level_following",
+            "follow",
+            "unfollow",
+            "my_friends",
+            "friends_of",
+            "request_friendship",
+            "decline_friendship",
+            "incoming_friendship_requests",
+            "outgoing_friendship_requests",
+            "drop_friendship",
+        ],
+    ),
+    (
+        "SocialContextDao",
+        1,
+        &[
+            "post",
+            "register_communication_method",
+            "writable",
+            "read_communications",
+            "get_communication_methods",
+            "members",
+            "join",
+            "leave",
+            "is_member",
+            "invite",
+            "accept_invite",
+            "decline_invite",
+            "pending_invites",
+            "get_context_info",
+            "update_context_info",
+            "pin_expression",
+            "unpin_expression",
+            "pinned",
+        ],
+    ),
+    (
+        "ExpressionDao",
+        2,
+        &[
+            "create_public_expression",
+            "get_by_author",
+            "get_expression_by_address",
+            "get_many_by_address",
+            "delete_expression",
+            "is_deleted",
+            "tombstones_since",
+            "boost",
+            "quote",
+            "boosts_of",
+            "quotes_of",
+            "send_private",
+            "pending_outbox",
+            "inbox",
+            "inbox_paged",
+            "mark_read",
+            "unread_count",
+            "delete_from_inbox",
+        ],
+    ),
+    (
+        "InterDNADao",
+        2,
+        &[
+            "create_link",
+            "create_links",
+            "remove_link",
+            "get_outgoing",
+            "get_incoming",
+            "get_outgoing_multi",
+            "get_all_outgoing",
+            "count_outgoing",
+            "count_incoming",
+            "translations_of",
+        ],
+    ),
+];
+
+impl TraitDescriptor {
+    /// Descriptor for one of the core traits of this crate at its current version, listing every method
+    pub fn core(name: &str) -> Option<Self> {
+        CORE_TRAITS
+            .iter()
+            .find(|(core_name, _, _)| *core_name == name)
+            .map(|(name, version, methods)| TraitDescriptor {
+                name: String::from(*name),
+                version: *version,
+                methods: methods.iter().map(|method| String::from(*method)).collect(),
+            })
+    }
+}
+
+/// Set of traits a DNA implements. DNAs build one to answer DescribeDao::describe; clients build one from
+/// the response to check a DNA supports what they need before calling it
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TraitRegistry {
+    traits: Vec<TraitDescriptor>,
+}
+
+impl TraitRegistry {
+    pub fn new() -> Self {
+        TraitRegistry::default()
+    }
+
+    /// Add a trait; replaces any descriptor already registered with the same name
+    pub fn register(mut self, descriptor: TraitDescriptor) -> Self {
+        self.traits
+            .retain(|registered| registered.name != descriptor.name);
+        self.traits.push(descriptor);
+        self
+    }
+
+    pub fn describe(&self) -> Vec<TraitDescriptor> {
+        self.traits.clone()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&TraitDescriptor> {
+        self.traits
+            .iter()
+            .find(|registered| registered.name == name)
+    }
+
+    /// Whether the trait is registered at min_version or above
+    pub fn implements(&self, name: &str, min_version: u32) -> bool {
+        self.get(name)
+            .map(|registered| registered.version >= min_version)
+            .unwrap_or(false)
+    }
+
+    /// Methods of required which are not exposed by the registered trait of the same name
+    pub fn missing_methods(&self, required: &TraitDescriptor) -> Vec<String> {
+        match self.get(&required.name) {
+            Some(registered) => required
+                .methods
+                .iter()
+                .filter(|method| !registered.methods.contains(method))
+                .cloned()
+                .collect(),
+            None => required.methods.clone(),
+        }
+    }
+}
+
+impl From<Vec<TraitDescriptor>> for TraitRegistry {
+    fn from(descriptors: Vec<TraitDescriptor>) -> Self {
+        descriptors
+            .into_iter()
+            .fold(TraitRegistry::new(), TraitRegistry::register)
+    }
+}
+
+/// Every DNA implementing traits from this crate should expose describe so clients can negotiate which traits
+/// and versions are available instead of failing on a missing zome function
+pub trait DescribeDao {
+    fn describe() -> ExternResult<Vec<TraitDescriptor>>;
+}
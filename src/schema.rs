@@ -0,0 +1,388 @@
+//! JSON Schemas for the serializable types of this crate so that implementations in other languages; i.e JS UIs
+//! and gateway services, can validate payloads against the same contract. Schemas describe the JSON form of each
+//! type.
+use schemars::gen::SchemaGenerator;
+use schemars::schema::{RootSchema, Schema};
+use schemars::{schema_for, JsonSchema};
+use std::collections::HashMap;
+
+/// Schema used in place of holo hash types, which serialize as their 39 raw bytes
+pub struct HoloHash;
+
+impl JsonSchema for HoloHash {
+    fn schema_name() -> String {
+        String::from("HoloHash")
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        let mut schema = <Vec<u8>>::json_schema(gen).into_object();
+        schema.array().min_items = Some(39);
+        schema.array().max_items = Some(39);
+        schema.into()
+    }
+}
+
+macro_rules! schemas {
+    ($schemas:ident { $($name:literal => $ty:ty,)* }) => {
+        $($schemas.insert(String::from($name), schema_for!($ty));)*
+    };
+}
+
+/// Schema of every serializable type in this crate, keyed by its path relative to the crate root;
+/// i.e "GlobalEntryRef", "activitypub::Activity" or "io::social_graph::FollowInput"
+pub fn export_schemas() -> HashMap<String, RootSchema> {
+    let mut schemas = HashMap::new();
+    schemas!(schemas {
+        "GlobalEntryRef" => crate::GlobalEntryRef,
+        "GlobalEntryRefChunked" => crate::GlobalEntryRefChunked,
+        "Expression" => crate::Expression,
+        "Audience" => crate::Audience,
+        "SensitivityCategory" => crate::SensitivityCategory,
+        "ContentWarning" => crate::ContentWarning,
+        "LanguageTag" => crate::LanguageTag,
+        "LanguageFilter" => crate::LanguageFilter,
+        "Ordering" => crate::Ordering,
+        "ExpressionPage" => crate::ExpressionPage,
+        "ContextVisibility" => crate::ContextVisibility,
+        "PostingPolicy" => crate::PostingPolicy,
+        "SocialContextProfile" => crate::SocialContextProfile,
+        "Role" => crate::Role,
+        "Capability" => crate::Capability,
+        "ContextInvite" => crate::ContextInvite,
+        "DeliveryStatus" => crate::DeliveryStatus,
+        "PendingMessage" => crate::PendingMessage,
+        "Tombstone" => crate::Tombstone,
+        "ReadReceipt" => crate::ReadReceipt,
+        "LinksByDna" => crate::LinksByDna,
+        "InterDnaLink" => crate::InterDnaLink,
+        "ValidationResult" => crate::ValidationResult,
+        "BatchResult" => crate::BatchResult,
+        "LinkType" => crate::LinkType,
+        "ReadToken" => crate::ReadToken,
+        "Attachment" => crate::Attachment,
+        "AttachmentLimits" => crate::AttachmentLimits,
+        "UnlinkabilityModel" => crate::UnlinkabilityModel,
+        "AnonymousPolicy" => crate::AnonymousPolicy,
+        "Tag" => crate::Tag,
+        "Draft" => crate::Draft,
+        "SealedExpression" => crate::SealedExpression,
+        "CallOutcome" => crate::CallOutcome,
+        "MirrorSegmentRef" => crate::MirrorSegmentRef,
+        "MirrorDescriptor" => crate::MirrorDescriptor,
+        "MirrorSegment" => crate::MirrorSegment,
+        "ConsentScope" => crate::ConsentScope,
+        "ConsentRecord" => crate::ConsentRecord,
+        "EncryptionScheme" => crate::EncryptionScheme,
+        "EncryptionSession" => crate::EncryptionSession,
+        "CiphertextMeta" => crate::CiphertextMeta,
+        "SearchQuery" => crate::SearchQuery,
+        "ReportReason" => crate::ReportReason,
+        "Resolution" => crate::Resolution,
+        "Report" => crate::Report,
+        "Poll" => crate::Poll,
+        "PollResults" => crate::PollResults,
+        "EventDetails" => crate::EventDetails,
+        "Event" => crate::Event,
+        "RsvpStatus" => crate::RsvpStatus,
+        "Attendee" => crate::Attendee,
+        "Device" => crate::Device,
+        "KeyRotationKind" => crate::KeyRotationKind,
+        "KeyRotationEvent" => crate::KeyRotationEvent,
+        "FnRef" => crate::FnRef,
+        "CapabilityGrant" => crate::CapabilityGrant,
+        "Claim" => crate::Claim,
+        "Attestation" => crate::Attestation,
+        "Notification" => crate::Notification,
+        "NotificationItem" => crate::NotificationItem,
+        "FeedReason" => crate::FeedReason,
+        "FeedItem" => crate::FeedItem,
+        "FeedPage" => crate::FeedPage,
+        "Conversation" => crate::Conversation,
+        "ConversationMessage" => crate::ConversationMessage,
+        "Room" => crate::Room,
+        "RoomMessage" => crate::RoomMessage,
+        "TypingSignal" => crate::TypingSignal,
+        "Status" => crate::Status,
+        "PresenceSignal" => crate::PresenceSignal,
+        "activitypub::ActivityFields" => crate::activitypub::ActivityFields,
+        "activitypub::Activity" => crate::activitypub::Activity,
+        "activitypub::PrivateAudience" => crate::activitypub::PrivateAudience,
+        "activitypub::DispatchTarget" => crate::activitypub::DispatchTarget,
+        "activitypub::DispatchRules" => crate::activitypub::DispatchRules,
+        "activitypub::InboxResult" => crate::activitypub::InboxResult,
+        "activitypub::PublicKey" => crate::activitypub::PublicKey,
+        "activitypub::WebFingerLink" => crate::activitypub::WebFingerLink,
+        "activitypub::WebFingerResource" => crate::activitypub::WebFingerResource,
+        "activitypub::Delivery" => crate::activitypub::Delivery,
+        "activitypub::UndoTarget" => crate::activitypub::UndoTarget,
+        "activitypub::JsonLdContext" => crate::activitypub::JsonLdContext,
+        "did::Did" => crate::did::Did,
+        "did::VerificationMethod" => crate::did::VerificationMethod,
+        "did::DidDocument" => crate::did::DidDocument,
+        "errors::TraitError" => crate::errors::TraitError,
+        "handle::Handle" => crate::handle::Handle,
+        "install::DnaBundleRef" => crate::install::DnaBundleRef,
+        "install::BridgeRequirement" => crate::install::BridgeRequirement,
+        "install::MembraneProofPrompt" => crate::install::MembraneProofPrompt,
+        "install::DnaDescriptor" => crate::install::DnaDescriptor,
+        "install::InstallStep" => crate::install::InstallStep,
+        "install::InstallPlan" => crate::install::InstallPlan,
+        "registry::TraitDescriptor" => crate::registry::TraitDescriptor,
+        "signals::Signal" => crate::signals::Signal,
+        "signals::SignalEnvelope" => crate::signals::SignalEnvelope,
+        "verification::VerificationError" => crate::verification::VerificationError,
+        "verification::ExpressionSignable" => crate::verification::ExpressionSignable,
+        "indexing::IndexPath" => crate::indexing::IndexPath,
+        "limits::RateLimit" => crate::limits::RateLimit,
+        "limits::Quota" => crate::limits::Quota,
+        "limits::DnaLimits" => crate::limits::DnaLimits,
+        "validation::ExpressionContent" => crate::validation::ExpressionContent,
+        "validation::ContentRules" => crate::validation::ContentRules,
+        "validation::ValidationIssue" => crate::validation::ValidationIssue,
+        "MembraneKind" => crate::MembraneKind,
+        "MembraneSpec" => crate::MembraneSpec,
+        "MembraneProof" => crate::MembraneProof,
+        "Invite" => crate::Invite,
+        "ReplyFilter" => crate::ReplyFilter,
+        "ExpressionFilter" => crate::ExpressionFilter,
+        "CoAuthorAcceptance" => crate::CoAuthorAcceptance,
+        "CoAuthorshipProposal" => crate::CoAuthorshipProposal,
+        "OnBehalfOf" => crate::OnBehalfOf,
+        "previews::UnfurlMetadata" => crate::previews::UnfurlMetadata,
+        "previews::LinkContent" => crate::previews::LinkContent,
+        "SubscriptionTarget" => crate::SubscriptionTarget,
+        "Subscription" => crate::Subscription,
+        "FilterMatch" => crate::FilterMatch,
+        "FilterAction" => crate::FilterAction,
+        "FilterRule" => crate::FilterRule,
+        "FeedFilter" => crate::FeedFilter,
+    });
+    crate::io::add_schemas(&mut schemas);
+    schemas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activitypub::{Activity, ActivityFields};
+    use crate::{Audience, Expression, GlobalEntryRef, Identity};
+    use hdk3::prelude::*;
+    use holo_hash::DnaHash;
+    use serde::de::DeserializeOwned;
+    use serde_json::Value;
+
+    /// Minimal validator covering the keywords schemars generates for this crate's types
+    fn validates(schema: &Value, definitions: &Value, value: &Value) -> bool {
+        if let Value::Bool(allowed) = schema {
+            return *allowed;
+        }
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let name = reference.trim_start_matches("#/definitions/");
+            return validates(&definitions[name], definitions, value);
+        }
+        if let Some(all) = schema.get("allOf").and_then(Value::as_array) {
+            if !all
+                .iter()
+                .all(|schema| validates(schema, definitions, value))
+            {
+                return false;
+            }
+        }
+        for keyword in ["anyOf", "oneOf"].iter() {
+            if let Some(any) = schema.get(*keyword).and_then(Value::as_array) {
+                if !any
+                    .iter()
+                    .any(|schema| validates(schema, definitions, value))
+                {
+                    return false;
+                }
+            }
+        }
+        if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+            if !options.contains(value) {
+                return false;
+            }
+        }
+        if let Some(types) = schema.get("type") {
+            let types: Vec<&str> = match types {
+                Value::String(kind) => vec![kind.as_str()],
+                Value::Array(kinds) => kinds.iter().filter_map(Value::as_str).collect(),
+                _ => vec![],
+            };
+            let matches = types.iter().any(|kind| match *kind {
+                "null" => value.is_null(),
+                "boolean" => value.is_boolean(),
+                "object" => value.is_object(),
+                "array" => value.is_array(),
+                "string" => value.is_string(),
+                "integer" => value.is_i64() || value.is_u64(),
+                "number" => value.is_number(),
+                _ => false,
+            });
+            if !matches {
+                return false;
+            }
+        }
+        if let Value::Object(object) = value {
+            let properties = schema.get("properties").and_then(Value::as_object);
+            let required = schema.get("required").and_then(Value::as_array);
+            if let Some(required) = required {
+                if !required
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .all(|key| object.contains_key(key))
+                {
+                    return false;
+                }
+            }
+            for (key, property) in object.iter() {
+                match properties.and_then(|properties| properties.get(key)) {
+                    Some(property_schema) => {
+                        if !validates(property_schema, definitions, property) {
+                            return false;
+                        }
+                    }
+                    None => {
+                        if schema.get("additionalProperties") == Some(&Value::Bool(false)) {
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+        if let Value::Array(items) = value {
+            match schema.get("items") {
+                Some(Value::Array(tuple)) => {
+                    if tuple.len() != items.len()
+                        || !tuple
+                            .iter()
+                            .zip(items.iter())
+                            .all(|(schema, item)| validates(schema, definitions, item))
+                    {
+                        return false;
+                    }
+                }
+                Some(item_schema) => {
+                    if !items
+                        .iter()
+                        .all(|item| validates(item_schema, definitions, item))
+                    {
+                        return false;
+                    }
+                }
+                None => {}
+            }
+            let length = items.len() as u64;
+            if schema
+                .get("minItems")
+                .and_then(Value::as_u64)
+                .map_or(false, |min| length < min)
+                || schema
+                    .get("maxItems")
+                    .and_then(Value::as_u64)
+                    .map_or(false, |max| length > max)
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Check the JSON form of sample matches the exported schema for name and survives a round-trip
+    fn check<T: Serialize + DeserializeOwned>(
+        schemas: &HashMap<String, RootSchema>,
+        name: &str,
+        sample: &T,
+    ) {
+        let schema = serde_json::to_value(
+            schemas
+                .get(name)
+                .unwrap_or_else(|| panic!("no schema exported for {}", name)),
+        )
+        .unwrap();
+        let json = serde_json::to_value(sample).unwrap();
+        assert!(
+            validates(&schema, &schema["definitions"], &json),
+            "{} does not match its schema: {}",
+            name,
+            json
+        );
+        let round_tripped: T = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(
+            serde_json::to_value(&round_tripped).unwrap(),
+            json,
+            "{} round-trip",
+            name
+        );
+    }
+
+    fn agent() -> Identity {
+        Identity::from_raw_bytes(vec![1; 36])
+    }
+
+    fn global_entry_ref() -> GlobalEntryRef {
+        GlobalEntryRef {
+            dna: DnaHash::from_raw_bytes(vec![2; 36]),
+            entry_address: HeaderHash::from_raw_bytes(vec![3; 36]),
+        }
+    }
+
+    fn expression() -> Expression {
+        let header = Header::Dna(Dna {
+            author: agent(),
+            timestamp: Timestamp(1_600_000_000, 0),
+            hash: DnaHash::from_raw_bytes(vec![2; 36]),
+        });
+        let header = HeaderHashed::with_pre_hashed(header, HeaderHash::from_raw_bytes(vec![3; 36]));
+        Expression {
+            expression: Element::new(
+                SignedHeaderHashed::with_presigned(header, Signature(vec![0; 64])),
+                None,
+            ),
+            expression_dna: DnaHash::from_raw_bytes(vec![2; 36]),
+            creator: agent(),
+            created_at: chrono::Utc::now(),
+            signature: Some(Signature(vec![0; 64])),
+            sensitivity: None,
+            language: Some(crate::LanguageTag::new("pt-BR").unwrap()),
+            audience: Audience::Agents(vec![agent()]),
+            authors: vec![],
+            on_behalf_of: None,
+        }
+    }
+
+    fn activity() -> Activity {
+        Activity::Create(ActivityFields {
+            id: Some(String::from("https://example.com/activities/1")),
+            actor: String::from("https://example.com/actors/alice"),
+            object: serde_json::json!({ "type": "Note", "content": "hello" }),
+            target: None,
+            to: vec![String::from(crate::AS_PUBLIC)],
+            cc: vec![],
+            bto: vec![],
+            bcc: vec![],
+            published: Some(chrono::Utc::now()),
+        })
+    }
+
+    #[test]
+    fn samples_match_exported_schemas() {
+        let schemas = export_schemas();
+        check(&schemas, "GlobalEntryRef", &global_entry_ref());
+        check(&schemas, "Expression", &expression());
+        check(&schemas, "activitypub::Activity", &activity());
+        check(
+            &schemas,
+            "io::social_graph::FollowInput",
+            &crate::io::social_graph::FollowInput {
+                target_agent: agent(),
+                by: Some(String::from("colleague")),
+            },
+        );
+        check(
+            &schemas,
+            "io::inter_dna::GetOutgoingOutput",
+            &crate::io::inter_dna::GetOutgoingOutput(vec![global_entry_ref()]),
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use hdk3::prelude::*;
+use holo_hash::DnaHash;
+
+use crate::{
+    ContextInvite, ConversationMessage, GlobalEntryRef, Identity, NotificationItem, PresenceSignal,
+    ReadReceipt, TypingSignal,
+};
+
+/// Version of the Signal format; incremented on any breaking change to Signal
+pub const SIGNAL_VERSION: u32 = 1;
+
+/// Every push event emitted by DNAs implementing the traits of this crate
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", content = "payload")]
+pub enum Signal {
+    NewExpressionInContext {
+        #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+        context: DnaHash,
+        expression: GlobalEntryRef,
+    },
+    PrivateExpressionReceived {
+        #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+        from: Identity,
+        #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+        expression: AnyDhtHash,
+    },
+    Follow {
+        #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+        by: Identity,
+    },
+    Reaction {
+        #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+        by: Identity,
+        expression: GlobalEntryRef,
+        reaction: String,
+    },
+    ContextInvite(ContextInvite),
+    ConversationMessage(ConversationMessage),
+    ReadReceipt(ReadReceipt),
+    Typing(TypingSignal),
+    Presence(PresenceSignal),
+    Notification(NotificationItem),
+}
+
+/// Wire format of signals; clients should check version before interpreting signal
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SignalEnvelope {
+    pub version: u32,
+    pub signal: Signal,
+}
+
+impl SignalEnvelope {
+    pub fn new(signal: Signal) -> Self {
+        SignalEnvelope {
+            version: SIGNAL_VERSION,
+            signal,
+        }
+    }
+}
+
+impl From<Signal> for SignalEnvelope {
+    fn from(signal: Signal) -> Self {
+        SignalEnvelope::new(signal)
+    }
+}
@@ -0,0 +1,122 @@
+//! Content rules which run identically in a DNA's validation callbacks and in clients before they attempt a write.
+use hdk3::prelude::*;
+
+use crate::previews::LinkContent;
+use crate::Attachment;
+
+/// Content of an expression as it is submitted for creation
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExpressionContent {
+    pub text: String,
+    pub attachments: Vec<Attachment>,
+    /// Set on link expressions
+    #[serde(default)]
+    pub link: Option<LinkContent>,
+}
+
+/// Rules expression content must satisfy. An empty allowed_mime_types allows any attachment type
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ContentRules {
+    /// Maximum length of text in characters
+    pub max_length: Option<usize>,
+    pub allowed_mime_types: Vec<String>,
+    /// Case insensitive substrings text may not contain. Empty patterns are ignored
+    pub forbidden_patterns: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ValidationIssue {
+    TooLong { length: usize, max_length: usize },
+    MimeTypeNotAllowed(String),
+    ForbiddenPattern(String),
+}
+
+/// Check content against rules, returning every issue found rather than only the first
+pub fn validate_expression_content(
+    content: &ExpressionContent,
+    rules: &ContentRules,
+) -> Result<(), Vec<ValidationIssue>> {
+    let mut issues = Vec::new();
+
+    let length = content.text.chars().count();
+    if let Some(max_length) = rules.max_length {
+        if length > max_length {
+            issues.push(ValidationIssue::TooLong { length, max_length });
+        }
+    }
+
+    if !rules.allowed_mime_types.is_empty() {
+        for attachment in content.attachments.iter() {
+            let allowed = rules
+                .allowed_mime_types
+                .iter()
+                .any(|mime_type| mime_type.eq_ignore_ascii_case(&attachment.mime_type));
+            if !allowed {
+                issues.push(ValidationIssue::MimeTypeNotAllowed(
+                    attachment.mime_type.clone(),
+                ));
+            }
+        }
+    }
+
+    let text = content.text.to_lowercase();
+    for pattern in rules
+        .forbidden_patterns
+        .iter()
+        .filter(|pattern| !pattern.is_empty())
+    {
+        if text.contains(&pattern.to_lowercase()) {
+            issues.push(ValidationIssue::ForbiddenPattern(pattern.clone()));
+        }
+    }
+
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn content(text: &str) -> ExpressionContent {
+        ExpressionContent {
+            text: String::from(text),
+            attachments: Vec::new(),
+            link: None,
+        }
+    }
+
+    fn forbidding(patterns: &[&str]) -> ContentRules {
+        ContentRules {
+            forbidden_patterns: patterns
+                .iter()
+                .map(|pattern| String::from(*pattern))
+                .collect(),
+            ..ContentRules::default()
+        }
+    }
+
+    #[test]
+    fn empty_forbidden_pattern_is_ignored() {
+        assert_eq!(
+            validate_expression_content(&content("hello"), &forbidding(&[""])),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn forbidden_pattern_matches_case_insensitively() {
+        assert_eq!(
+            validate_expression_content(&content("Hello World"), &forbidding(&["", "WORLD"])),
+            Err(vec![ValidationIssue::ForbiddenPattern(String::from(
+                "WORLD"
+            ))])
+        );
+    }
+}
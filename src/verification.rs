@@ -0,0 +1,68 @@
+use hdk3::prelude::*;
+use holo_hash::DnaHash;
+
+use crate::Expression;
+
+/// Reasons an expression's authorship could not be verified
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum VerificationError {
+    MissingSignature,
+    /// The expression's element was not authored by the expression's creator
+    AuthorMismatch,
+    InvalidSignature,
+    /// The expression's element has no entry, so there is no content for a signature to cover
+    MissingEntry,
+    /// Host failed while verifying the signature
+    Host(String),
+}
+
+/// Data an expression's creator signs to produce Expression.signature.
+/// Signing the entry hash rather than the element allows the signature to survive the expression being forwarded to other DNAs.
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExpressionSignable {
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub entry_hash: EntryHash,
+    #[cfg_attr(feature = "schema", schemars(with = "crate::schema::HoloHash"))]
+    pub expression_dna: DnaHash,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl ExpressionSignable {
+    /// Fails for an expression whose element has no entry; a signature over only its DNA and creation time could
+    /// be replayed over any content
+    pub fn from_expression(expression: &Expression) -> Result<Self, VerificationError> {
+        let (entry_hash, _) = expression
+            .expression
+            .header()
+            .entry_data()
+            .ok_or(VerificationError::MissingEntry)?;
+        Ok(ExpressionSignable {
+            entry_hash: entry_hash.clone(),
+            expression_dna: expression.expression_dna.clone(),
+            created_at: expression.created_at,
+        })
+    }
+}
+
+/// Verify that expression was signed by its creator
+pub fn verify_authorship(expression: &Expression) -> Result<(), VerificationError> {
+    let signature = expression
+        .signature
+        .as_ref()
+        .ok_or(VerificationError::MissingSignature)?;
+    if expression.expression.header().author() != &expression.creator {
+        return Err(VerificationError::AuthorMismatch);
+    }
+
+    match verify_signature(
+        expression.creator.clone(),
+        signature.clone(),
+        ExpressionSignable::from_expression(expression)?,
+    ) {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(VerificationError::InvalidSignature),
+        Err(err) => Err(VerificationError::Host(format!("{:?}", err))),
+    }
+}